@@ -0,0 +1,285 @@
+//! Stream unified-database tables out as Apache Arrow `RecordBatch`es, and optionally write
+//! them to Parquet files, so extracted data loads directly into pandas/DuckDB/polars without
+//! going through SQLite. Behind the `arrow` Cargo feature, since it pulls in the `arrow`/
+//! `parquet` crates that a caller only exporting JSON/CSV/YAML (see [`crate::export`]) doesn't
+//! need. Shares [`crate::export::EXPORTABLE_TABLES`]'s column lists so the two export paths
+//! can't drift apart as tables are added.
+
+use crate::error::{Error, Result};
+use crate::export::{selected_tables, TableSpec, EXPORTABLE_TABLES};
+use arrow::array::{ArrayRef, Float64Builder, Int64Builder, StringBuilder, TimestampSecondBuilder};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Rows per `RecordBatch`, chosen so a multi-million-row `messages` table never has to be
+/// materialized in memory all at once.
+const BATCH_SIZE: usize = 64 * 1024;
+
+/// Build the Arrow [`Schema`] for `spec`: each of `spec.timestamp_columns` maps to
+/// `Timestamp(Second)` (mirroring how [`crate::export::export_table`] renders those columns as
+/// RFC3339 rather than a raw integer); every other column maps to `Int64`/`Float64`/`Utf8` by
+/// its actual SQLite column type affinity (`sqlite_master`/`pragma_table_info` declared type),
+/// falling back to `Utf8` for anything SQLite's loose typing doesn't pin down.
+fn table_schema(conn: &Connection, spec: &TableSpec) -> Result<SchemaRef> {
+    let mut stmt = conn.prepare("SELECT name, type FROM pragma_table_info(?1)")?;
+    let mut rows = stmt.query(rusqlite::params![spec.name])?;
+    let mut affinities: HashMap<String, String> = HashMap::new();
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(0)?;
+        let decl_type: String = row.get(1)?;
+        affinities.insert(name, decl_type.to_uppercase());
+    }
+
+    let fields = spec
+        .columns
+        .iter()
+        .map(|col| {
+            let data_type = if spec.timestamp_columns.contains(col) {
+                DataType::Timestamp(TimeUnit::Second, None)
+            } else {
+                match affinities.get(*col).map(String::as_str) {
+                    Some("INTEGER") => DataType::Int64,
+                    Some("REAL") => DataType::Float64,
+                    _ => DataType::Utf8,
+                }
+            };
+            Field::new(*col, data_type, true)
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+/// A column's in-progress Arrow array, built up one SQLite row at a time and finished into an
+/// [`ArrayRef`] once a batch is full.
+enum ColumnBuilder {
+    Int64(Int64Builder),
+    Float64(Float64Builder),
+    Utf8(StringBuilder),
+    TimestampSecond(TimestampSecondBuilder),
+}
+
+impl ColumnBuilder {
+    fn new(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Int64 => ColumnBuilder::Int64(Int64Builder::new()),
+            DataType::Float64 => ColumnBuilder::Float64(Float64Builder::new()),
+            DataType::Timestamp(TimeUnit::Second, None) => {
+                ColumnBuilder::TimestampSecond(TimestampSecondBuilder::new())
+            }
+            _ => ColumnBuilder::Utf8(StringBuilder::new()),
+        }
+    }
+
+    fn append(&mut self, row: &rusqlite::Row<'_>, idx: usize) -> Result<()> {
+        match self {
+            ColumnBuilder::Int64(b) => b.append_option(row.get::<_, Option<i64>>(idx)?),
+            ColumnBuilder::Float64(b) => b.append_option(row.get::<_, Option<f64>>(idx)?),
+            ColumnBuilder::TimestampSecond(b) => b.append_option(row.get::<_, Option<i64>>(idx)?),
+            ColumnBuilder::Utf8(b) => b.append_option(row.get::<_, Option<String>>(idx)?),
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> ArrayRef {
+        match self {
+            ColumnBuilder::Int64(mut b) => Arc::new(b.finish()),
+            ColumnBuilder::Float64(mut b) => Arc::new(b.finish()),
+            ColumnBuilder::TimestampSecond(mut b) => Arc::new(b.finish()),
+            ColumnBuilder::Utf8(mut b) => Arc::new(b.finish()),
+        }
+    }
+}
+
+/// Read `spec`'s table from `conn` and call `on_batch` once per [`BATCH_SIZE`]-row chunk (the
+/// last chunk may be smaller), so a caller can write each batch out (e.g. to Parquet) without
+/// ever holding the whole table in memory.
+pub fn stream_table(
+    conn: &Connection,
+    spec: &TableSpec,
+    schema: &SchemaRef,
+    mut on_batch: impl FnMut(RecordBatch) -> Result<()>,
+) -> Result<()> {
+    let sql = format!("SELECT {} FROM {}", spec.columns.join(", "), spec.name);
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query([])?;
+
+    loop {
+        let mut builders: Vec<ColumnBuilder> = schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::new(f.data_type()))
+            .collect();
+
+        let mut n = 0;
+        while n < BATCH_SIZE {
+            let Some(row) = rows.next()? else {
+                break;
+            };
+            for (idx, builder) in builders.iter_mut().enumerate() {
+                builder.append(row, idx)?;
+            }
+            n += 1;
+        }
+        if n == 0 {
+            break;
+        }
+
+        let arrays: Vec<ArrayRef> = builders.into_iter().map(ColumnBuilder::finish).collect();
+        let batch = RecordBatch::try_new(schema.clone(), arrays).map_err(|e| {
+            Error::ExtractionFailed(format!(
+                "Failed to build Arrow batch for {}: {}",
+                spec.name, e
+            ))
+        })?;
+        on_batch(batch)?;
+
+        if n < BATCH_SIZE {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write every table in [`EXPORTABLE_TABLES`] (or, if `tables` is `Some`, just the named subset
+/// — see [`crate::types::ExtractionConfig::export_tables`]) from the unified database at
+/// `output_dir/unified.db` to its own `<table>.parquet` file under `dest_dir`. Returns the paths
+/// written.
+///
+/// `encryption_key` must match whatever the database was extracted with (`None` for a
+/// plaintext database), so it can be decrypted the same way extraction did before reading.
+pub fn write_parquet(
+    output_dir: &Path,
+    dest_dir: &Path,
+    tables: Option<&[String]>,
+    encryption_key: Option<&str>,
+) -> Result<Vec<PathBuf>> {
+    let db_path = output_dir.join("unified.db");
+    if !db_path.exists() {
+        return Err(Error::database_context(
+            "write_parquet",
+            db_path.display().to_string(),
+            "database does not exist",
+            "Run an extraction first to create unified.db",
+        ));
+    }
+
+    std::fs::create_dir_all(dest_dir)
+        .map_err(|e| Error::io_context("write_parquet", dest_dir.display().to_string(), e))?;
+
+    crate::with_unified_db(output_dir, encryption_key, |conn| {
+        write_parquet_tables(conn, dest_dir, tables)
+    })
+}
+
+fn write_parquet_tables(
+    conn: &Connection,
+    dest_dir: &Path,
+    tables: Option<&[String]>,
+) -> Result<Vec<PathBuf>> {
+    let mut written = Vec::new();
+    for spec in selected_tables(tables) {
+        let dest = dest_dir.join(format!("{}.parquet", spec.name));
+        let schema = table_schema(&conn, spec)?;
+
+        let file = File::create(&dest)
+            .map_err(|e| Error::io_context("write_parquet", dest.display().to_string(), e))?;
+        let mut writer = ArrowWriter::try_new(file, schema.clone(), None).map_err(|e| {
+            Error::ExtractionFailed(format!(
+                "Failed to open Parquet writer for {}: {}",
+                spec.name, e
+            ))
+        })?;
+
+        stream_table(&conn, spec, &schema, |batch| {
+            writer.write(&batch).map_err(|e| {
+                Error::ExtractionFailed(format!(
+                    "Failed to write Parquet batch for {}: {}",
+                    spec.name, e
+                ))
+            })
+        })?;
+
+        writer.close().map_err(|e| {
+            Error::ExtractionFailed(format!(
+                "Failed to close Parquet file for {}: {}",
+                spec.name, e
+            ))
+        })?;
+        written.push(dest);
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+    use std::fs;
+
+    #[test]
+    fn test_write_parquet_writes_one_file_per_table() {
+        let dir = std::env::temp_dir().join(format!(
+            "quantified-core-arrow-export-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let output_dir = dir.join("out");
+        fs::create_dir_all(&output_dir).unwrap();
+
+        let conn = Connection::open(output_dir.join("unified.db")).unwrap();
+        schema::init_database(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO messages (record_hash, text, is_from_me, timestamp) VALUES (?, ?, ?, ?)",
+            rusqlite::params!["hash1", "hello", 1, 1_672_531_200i64],
+        )
+        .unwrap();
+        drop(conn);
+
+        let dest_dir = dir.join("export");
+        let written = write_parquet(&output_dir, &dest_dir, None, None).unwrap();
+
+        assert_eq!(written.len(), EXPORTABLE_TABLES.len());
+        assert!(dest_dir.join("messages.parquet").exists());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_write_parquet_filters_by_table_name() {
+        let dir = std::env::temp_dir().join(format!(
+            "quantified-core-arrow-export-filter-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let output_dir = dir.join("out");
+        fs::create_dir_all(&output_dir).unwrap();
+
+        let conn = Connection::open(output_dir.join("unified.db")).unwrap();
+        schema::init_database(&conn).unwrap();
+        drop(conn);
+
+        let dest_dir = dir.join("export");
+        let written = write_parquet(
+            &output_dir,
+            &dest_dir,
+            Some(&["messages".to_string()]),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(written.len(), 1);
+        assert!(dest_dir.join("messages.parquet").exists());
+        assert!(!dest_dir.join("web_visits.parquet").exists());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -1,7 +1,8 @@
 //! Command-line interface for quantified-core extraction
 
 use quantified_core::{
-    extract_all, extract_source, CollectorType, CoreExtractionConfig as ExtractionConfig,
+    extract_all, extract_all_parallel, extract_source, watch, CollectorType,
+    CoreExtractionConfig as ExtractionConfig,
 };
 use std::env;
 use std::path::PathBuf;
@@ -15,19 +16,41 @@ fn print_usage() {
     eprintln!("Options:");
     eprintln!("  -o, --output <DIR>    Output directory (default: ./data)");
     eprintln!("  -q, --quiet           Suppress verbose output");
+    eprintln!(
+        "  -w, --watch           Keep running, re-extracting collectors as their sources change"
+    );
+    eprintln!("  -p, --parallel        Extract all sources concurrently instead of one at a time");
+    eprintln!("  --devtools-host <HOST> Chrome DevTools endpoint host for chrome_live (default: 127.0.0.1)");
+    eprintln!(
+        "  --devtools-port <PORT> Chrome DevTools endpoint port for chrome_live (default: 9222)"
+    );
     eprintln!("  -h, --help            Show this help message");
     eprintln!();
     eprintln!("Collectors:");
     eprintln!("  messages              Extract iMessage/SMS data");
     eprintln!("  chrome                Extract Chrome browsing history");
+    eprintln!(
+        "  chrome_live           Capture currently open tabs via the Chrome DevTools Protocol"
+    );
+    eprintln!("  firefox               Extract Firefox browsing history");
+    eprintln!("  safari                Extract Safari browsing history");
+    eprintln!("  brave                 Extract Brave browsing history");
+    eprintln!("  edge                  Extract Microsoft Edge browsing history");
     eprintln!("  knowledgec            Extract app usage, bluetooth, etc.");
     eprintln!("  podcasts              Extract Apple Podcasts history");
-    eprintln!("  all                   Extract all sources (default)");
+    eprintln!("  all                   Extract all sources (default, excludes chrome_live)");
     eprintln!();
     eprintln!("Examples:");
     eprintln!("  quantified-core                    # Extract all sources");
     eprintln!("  quantified-core messages           # Extract only messages");
     eprintln!("  quantified-core -o ~/data chrome   # Extract Chrome to ~/data");
+    eprintln!(
+        "  quantified-core --watch messages   # Keep re-extracting messages as chat.db changes"
+    );
+    eprintln!("  quantified-core chrome_live --devtools-port 9222   # Capture live Chrome tabs");
+    eprintln!(
+        "  quantified-core --parallel                         # Extract all sources concurrently"
+    );
 }
 
 fn main() {
@@ -35,6 +58,10 @@ fn main() {
 
     let mut output_dir = PathBuf::from("data");
     let mut verbose = true;
+    let mut watch_mode = false;
+    let mut parallel = false;
+    let mut devtools_host: Option<String> = None;
+    let mut devtools_port: Option<u16> = None;
     let mut collector_name: Option<String> = None;
 
     let mut i = 1;
@@ -56,6 +83,36 @@ fn main() {
                 verbose = false;
                 i += 1;
             }
+            "-w" | "--watch" => {
+                watch_mode = true;
+                i += 1;
+            }
+            "-p" | "--parallel" => {
+                parallel = true;
+                i += 1;
+            }
+            "--devtools-host" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Error: --devtools-host requires a hostname");
+                    process::exit(1);
+                }
+                devtools_host = Some(args[i + 1].clone());
+                i += 2;
+            }
+            "--devtools-port" => {
+                if i + 1 >= args.len() {
+                    eprintln!("Error: --devtools-port requires a port number");
+                    process::exit(1);
+                }
+                devtools_port = match args[i + 1].parse() {
+                    Ok(port) => Some(port),
+                    Err(_) => {
+                        eprintln!("Error: invalid port: {}", args[i + 1]);
+                        process::exit(1);
+                    }
+                };
+                i += 2;
+            }
             arg if !arg.starts_with('-') => {
                 collector_name = Some(arg.to_string());
                 i += 1;
@@ -70,7 +127,12 @@ fn main() {
     }
 
     // Create config
-    let config = ExtractionConfig::with_output_dir(output_dir).verbose(verbose);
+    let mut config = ExtractionConfig::with_output_dir(output_dir).verbose(verbose);
+    if devtools_host.is_some() || devtools_port.is_some() {
+        let host = devtools_host.unwrap_or_else(|| config.chrome_live_host.clone());
+        let port = devtools_port.unwrap_or(config.chrome_live_port);
+        config = config.with_chrome_live_endpoint(host, port);
+    }
 
     println!();
     println!("╔══════════════════════════════════════════════════════════╗");
@@ -78,11 +140,37 @@ fn main() {
     println!("╚══════════════════════════════════════════════════════════╝");
     println!();
 
+    if watch_mode {
+        let collector_types = match collector_name.as_deref() {
+            None | Some("all") => CollectorType::all(),
+            Some(name) => match CollectorType::from_str(name) {
+                Some(ct) => vec![ct],
+                None => {
+                    eprintln!("Error: Unknown collector: {}", name);
+                    eprintln!("Available collectors: messages, chrome, chrome_live, firefox, safari, brave, edge, knowledgec, podcasts");
+                    process::exit(1);
+                }
+            },
+        };
+
+        if let Err(e) = watch(&config, &collector_types) {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+
+        return;
+    }
+
     // Run extraction
     let results = match collector_name.as_deref() {
         None | Some("all") => {
             // Extract all sources
-            match extract_all(&config) {
+            let all_result = if parallel {
+                extract_all_parallel(&config)
+            } else {
+                extract_all(&config)
+            };
+            match all_result {
                 Ok(results) => results,
                 Err(e) => {
                     eprintln!("Error: {}", e);
@@ -96,7 +184,9 @@ fn main() {
                 Some(ct) => ct,
                 None => {
                     eprintln!("Error: Unknown collector: {}", name);
-                    eprintln!("Available collectors: messages, chrome, knowledgec, podcasts");
+                    eprintln!(
+                        "Available collectors: messages, chrome, chrome_live, firefox, safari, brave, edge, knowledgec, podcasts"
+                    );
                     process::exit(1);
                 }
             };
@@ -134,6 +224,7 @@ fn main() {
                 "✗"
             }
             quantified_core::types::ExtractionStatus::Running => "⋯",
+            quantified_core::types::ExtractionStatus::Cancelled => "⊘",
         };
 
         println!(
@@ -0,0 +1,259 @@
+//! Per-run changesets for incremental re-extraction, via rusqlite's `session` feature.
+//!
+//! Investigators often re-run extraction against a refreshed copy of the same device and want
+//! to know exactly what's new since the last run. [`capture`] attaches a SQLite
+//! [`Session`](rusqlite::session::Session) to every table a collector can write to, runs the
+//! extraction inside it, and returns the resulting changeset (empty if nothing changed) alongside
+//! whatever the wrapped closure returned. [`extract_source`](crate::extract_source) persists that
+//! changeset into `run_changesets`, keyed by the run's `extraction_runs.id`, when
+//! `ExtractionConfig::capture_changesets` is set. [`summarize`] turns a stored changeset into a
+//! per-table insert/update/delete count for "what changed since last extraction" reports;
+//! [`apply`] and [`revert`] let a changeset be replayed or undone without rebuilding the whole
+//! unified database.
+
+use crate::error::Result;
+use fallible_streaming_iterator::FallibleStreamingIterator;
+use rusqlite::session::{ChangesetIter, ConflictAction, ConflictType};
+use rusqlite::{Connection, OptionalExtension};
+use std::collections::BTreeMap;
+
+/// Tables a collector can write to, and therefore the tables a re-extraction changeset should
+/// track. Kept in sync with the `CREATE TABLE` statements in [`crate::schema`].
+const TRACKED_TABLES: &[&str] = &[
+    "app_usage",
+    "web_visits",
+    "bluetooth_connections",
+    "notifications",
+    "messages",
+    "chats",
+    "contacts",
+    "podcast_episodes",
+    "podcast_shows",
+    "intents",
+    "display_state",
+    "knowledgec_events",
+];
+
+/// Run `f` with a [`Session`](rusqlite::session::Session) attached to every tracked table and
+/// return whatever `f` returned alongside the resulting changeset (empty if nothing changed). A
+/// rolled-back transaction is excluded by SQLite itself (a session only ever reflects changes
+/// that actually committed), so this can safely wrap a whole `Collector::run()` regardless of
+/// how it ends.
+pub fn capture<F, T>(conn: &Connection, f: F) -> Result<(T, Vec<u8>)>
+where
+    F: FnOnce() -> Result<T>,
+{
+    let mut session = rusqlite::session::Session::new(conn)?;
+    for table in TRACKED_TABLES {
+        session.attach(Some(table))?;
+    }
+
+    let value = f()?;
+
+    if session.is_empty() {
+        return Ok((value, Vec::new()));
+    }
+
+    let mut buf = Vec::new();
+    session.changeset_strm(&mut buf)?;
+    Ok((value, buf))
+}
+
+/// Per-table row-change counts extracted from a changeset, for a "what changed since last
+/// extraction" report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangesetSummary {
+    pub table_changes: BTreeMap<String, usize>,
+}
+
+impl ChangesetSummary {
+    pub fn total_changes(&self) -> usize {
+        self.table_changes.values().sum()
+    }
+}
+
+/// Summarize a stored changeset into a count of changed rows per table.
+pub fn summarize(changeset_bytes: &[u8]) -> Result<ChangesetSummary> {
+    let mut input = changeset_bytes;
+    let reader: &mut dyn std::io::Read = &mut input;
+    let mut iter = ChangesetIter::start_strm(&reader)?;
+    let mut table_changes = BTreeMap::new();
+
+    while let Some(item) = iter.next()? {
+        let op = item.op()?;
+        *table_changes
+            .entry(op.table_name().to_string())
+            .or_insert(0) += 1;
+    }
+
+    Ok(ChangesetSummary { table_changes })
+}
+
+/// Diff two previously captured run changesets by comparing their per-table change counts.
+/// Positive values in the result mean `run_b` touched more rows in that table than `run_a`.
+pub fn diff(run_a: &[u8], run_b: &[u8]) -> Result<BTreeMap<String, i64>> {
+    let a = summarize(run_a)?;
+    let b = summarize(run_b)?;
+
+    let mut tables: Vec<&String> = a
+        .table_changes
+        .keys()
+        .chain(b.table_changes.keys())
+        .collect();
+    tables.sort();
+    tables.dedup();
+
+    let mut delta = BTreeMap::new();
+    for table in tables {
+        let a_count = *a.table_changes.get(table).unwrap_or(&0) as i64;
+        let b_count = *b.table_changes.get(table).unwrap_or(&0) as i64;
+        delta.insert(table.clone(), b_count - a_count);
+    }
+
+    Ok(delta)
+}
+
+/// Re-apply a previously captured changeset, e.g. to replay a run onto a fresh copy of the
+/// unified database. Conflicts (a row the changeset wants to insert already exists) are
+/// resolved by keeping what's already there, since the rows involved are already
+/// content-addressed by `record_hash`.
+pub fn apply(conn: &Connection, changeset_bytes: &[u8]) -> Result<()> {
+    let mut input = changeset_bytes;
+    conn.apply_strm(
+        &mut input,
+        None::<fn(&str) -> bool>,
+        |_conflict: ConflictType, _item| ConflictAction::SQLITE_CHANGESET_OMIT,
+    )?;
+    Ok(())
+}
+
+/// Undo a previously applied changeset by inverting it and applying the inverse, so an
+/// erroneous import can be rolled back without rebuilding the whole unified database.
+pub fn revert(conn: &Connection, changeset_bytes: &[u8]) -> Result<()> {
+    let mut input = changeset_bytes;
+    let mut inverted = Vec::new();
+    rusqlite::session::invert_strm(&mut input, &mut inverted)?;
+
+    let mut inverted_input = inverted.as_slice();
+    conn.apply_strm(
+        &mut inverted_input,
+        None::<fn(&str) -> bool>,
+        |_conflict: ConflictType, _item| ConflictAction::SQLITE_CHANGESET_OMIT,
+    )?;
+    Ok(())
+}
+
+/// Persist a run's changeset into `run_changesets`. A no-op if `changeset_bytes` is empty (the
+/// run added nothing), so the table only ever holds changesets worth keeping.
+pub fn record(conn: &Connection, run_id: i64, changeset_bytes: &[u8]) -> Result<()> {
+    if changeset_bytes.is_empty() {
+        return Ok(());
+    }
+
+    conn.execute(
+        "INSERT INTO run_changesets (run_id, changeset, created_at) VALUES (?, ?, ?)",
+        rusqlite::params![run_id, changeset_bytes, crate::timestamp::now_unix()],
+    )?;
+    Ok(())
+}
+
+/// Load a previously stored changeset for `run_id`, if one was recorded.
+pub fn load(conn: &Connection, run_id: i64) -> Result<Option<Vec<u8>>> {
+    conn.query_row(
+        "SELECT changeset FROM run_changesets WHERE run_id = ?",
+        rusqlite::params![run_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+
+    #[test]
+    fn test_capture_records_inserted_rows() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::init_database(&conn).unwrap();
+
+        let (_, changeset) = capture(&conn, || {
+            conn.execute(
+                "INSERT INTO contacts (record_hash, handle_id) VALUES (?, ?)",
+                rusqlite::params!["abc", "+15555550123"],
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(!changeset.is_empty());
+
+        let summary = summarize(&changeset).unwrap();
+        assert_eq!(summary.table_changes.get("contacts"), Some(&1));
+    }
+
+    #[test]
+    fn test_capture_is_empty_when_nothing_changes() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::init_database(&conn).unwrap();
+
+        let (_, changeset) = capture(&conn, || Ok(())).unwrap();
+        assert!(changeset.is_empty());
+    }
+
+    #[test]
+    fn test_revert_undoes_an_applied_changeset() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::init_database(&conn).unwrap();
+
+        let (_, changeset) = capture(&conn, || {
+            conn.execute(
+                "INSERT INTO contacts (record_hash, handle_id) VALUES (?, ?)",
+                rusqlite::params!["abc", "+15555550123"],
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        let count_before: i64 = conn
+            .query_row("SELECT COUNT(*) FROM contacts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count_before, 1);
+
+        revert(&conn, &changeset).unwrap();
+
+        let count_after: i64 = conn
+            .query_row("SELECT COUNT(*) FROM contacts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count_after, 0);
+    }
+
+    #[test]
+    fn test_record_and_load_roundtrip() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::init_database(&conn).unwrap();
+
+        let (_, changeset) = capture(&conn, || {
+            conn.execute(
+                "INSERT INTO contacts (record_hash, handle_id) VALUES (?, ?)",
+                rusqlite::params!["abc", "+15555550123"],
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        record(&conn, 1, &changeset).unwrap();
+        let loaded = load(&conn, 1).unwrap();
+        assert_eq!(loaded, Some(changeset));
+    }
+
+    #[test]
+    fn test_record_skips_empty_changeset() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::init_database(&conn).unwrap();
+
+        record(&conn, 1, &[]).unwrap();
+        assert_eq!(load(&conn, 1).unwrap(), None);
+    }
+}
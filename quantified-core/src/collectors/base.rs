@@ -1,9 +1,12 @@
 //! Base collector trait and common functionality for all collectors
 
 use crate::error::{Error, Result};
-use crate::timestamp;
+use crate::integrity;
+use crate::metrics::Metrics;
+use crate::storage::{SqliteBackend, StorageBackend};
 use crate::types::{ExtractionConfig, ExtractionResult};
 use rusqlite::Connection;
+use std::collections::HashSet;
 use std::fs;
 use std::path::PathBuf;
 
@@ -15,8 +18,25 @@ pub trait Collector {
     /// Get the source paths to search for this collector's database
     fn source_paths(&self) -> Vec<String>;
 
-    /// Extract data from the source database into the unified database
-    fn extract(&mut self, source_conn: &Connection) -> Result<()>;
+    /// Extract data from the source database into the unified database, recording per
+    /// sub-step progress (rows scanned/added/skipped/dropped, duration) into `metrics`.
+    fn extract(&mut self, source_conn: &Connection, metrics: &mut Metrics) -> Result<()>;
+
+    /// Like [`Collector::extract`], but told which of [`Collector::source_dbs`] it's reading
+    /// from (`""` for a collector with only ever one source). Defaults to ignoring the label and
+    /// delegating to `extract`; a collector that discovers multiple source databases (see
+    /// [`ChromeCollector`](crate::collectors::chrome::ChromeCollector)) overrides this to tag the
+    /// rows it writes with the originating source, the same way
+    /// [`ChromeLiveCollector`](crate::collectors::chrome_live::ChromeLiveCollector) overrides
+    /// `run` wholesale for a source that isn't a file at all.
+    fn extract_profile(
+        &mut self,
+        source_conn: &Connection,
+        metrics: &mut Metrics,
+        _label: &str,
+    ) -> Result<()> {
+        self.extract(source_conn, metrics)
+    }
 
     /// Best-effort: resolve which source database path would be used for this collector.
     ///
@@ -25,6 +45,19 @@ pub trait Collector {
         self.find_source_db().map(|p| p.display().to_string())
     }
 
+    /// Every source database this collector should extract from this run, paired with a label
+    /// identifying it (`""` when there's only ever one, e.g. Messages' single `chat.db`).
+    /// Defaults to wrapping [`Collector::find_source_db`]'s single result. Chrome overrides this
+    /// to return every discovered profile instead of just the most recently modified one; this is
+    /// the same seam Messages/Podcasts could use later if they ever need to read more than one
+    /// source database.
+    fn source_dbs(&self) -> Vec<(PathBuf, String)> {
+        match self.find_source_db() {
+            Some(path) => vec![(path, String::new())],
+            None => Vec::new(),
+        }
+    }
+
     /// Run the complete extraction pipeline
     fn run(&mut self) -> Result<ExtractionResult> {
         let result = ExtractionResult::new(self.name().to_string());
@@ -35,36 +68,75 @@ pub trait Collector {
             println!("{}", "=".repeat(50));
         }
 
-        // Find and copy source database
-        let source_path = match self.find_source_db() {
-            Some(path) => path,
-            None => {
-                let error_msg = format!("Source database not found for {}", self.name());
-                if self.verbose() {
-                    println!("  ✗ {}", error_msg);
-                }
-                return Ok(result.fail(error_msg));
+        // Discover every source database this collector should read this run (almost always
+        // exactly one; Chrome may find several profiles).
+        let source_dbs = self.source_dbs();
+        if source_dbs.is_empty() {
+            let error_msg = format!("Source database not found for {}", self.name());
+            if self.verbose() {
+                println!("  ✗ {}", error_msg);
             }
-        };
+            return Ok(result.fail(error_msg));
+        }
 
-        let source_db_copy = match self.copy_source_db(&source_path) {
-            Ok(path) => path,
-            Err(e) => {
-                let error_msg = format!("Failed to copy source database: {}", e);
-                if self.verbose() {
-                    println!("  ✗ {}", error_msg);
+        // Start extraction run in database
+        let run_id = self.start_extraction_run()?;
+        self.config().progress.report_started(self.name());
+
+        #[cfg(feature = "otel")]
+        let run_started = std::time::Instant::now();
+
+        // Copy, integrity-check and extract each source database in turn, inside one transaction
+        // so a multi-year source doesn't pay an implicit commit (and fsync) per row. A source
+        // that fails to copy or check out is skipped rather than aborting the whole run, since
+        // one stale/locked Chrome profile shouldn't cost the others their data; the run only
+        // fails outright if every source database failed. A cancellation (checked between
+        // sources, and again at each [`BaseCollector::insert_dedup`] batch boundary) aborts the
+        // whole run immediately instead, since the caller asked to stop.
+        let mut metrics = Metrics::new();
+        let mut succeeded_any = false;
+        let mut last_error: Option<Error> = None;
+        let extract_result = (|| {
+            self.unified_db().execute_batch("BEGIN")?;
+
+            for (source_path, label) in &source_dbs {
+                if self.config().progress.is_cancelled() {
+                    return Err(Error::Cancelled);
+                }
+
+                #[cfg(feature = "otel")]
+                let _span =
+                    crate::otel::start_span(self.name(), &source_path.display().to_string());
+
+                #[cfg(feature = "tracing")]
+                let _tracing_span = crate::tracing_support::collector_span(
+                    self.name(),
+                    "extract_one_source",
+                    &source_path.display().to_string(),
+                )
+                .entered();
+
+                match self.extract_one_source(source_path, label, &mut metrics) {
+                    Ok(()) => succeeded_any = true,
+                    Err(Error::Cancelled) => return Err(Error::Cancelled),
+                    Err(e) => {
+                        if self.verbose() {
+                            println!("  ✗ {}", e);
+                        }
+                        #[cfg(feature = "tracing")]
+                        tracing::error!(error = %e, "collector extraction failed");
+                        last_error = Some(e);
+                    }
                 }
-                return Ok(result.fail(error_msg));
             }
-        };
 
-        // Start extraction run in database
-        let run_id = self.start_extraction_run()?;
+            if !succeeded_any {
+                if let Some(e) = last_error.take() {
+                    return Err(e);
+                }
+            }
 
-        // Open source database and run extraction
-        let extract_result = (|| {
-            let source_conn = Connection::open(&source_db_copy)?;
-            self.extract(&source_conn)?;
+            self.unified_db().execute_batch("COMMIT")?;
             Ok::<(), Error>(())
         })();
 
@@ -72,27 +144,138 @@ pub trait Collector {
         match extract_result {
             Ok(_) => {
                 let (added, skipped) = self.get_counts();
-                self.complete_extraction_run(run_id, "completed", added, skipped)?;
+
+                let deleted = if self.config().reconcile_deletions {
+                    self.reconcile_deletions(&self.seen_source_ids())?
+                } else {
+                    0
+                };
+
+                self.complete_extraction_run(run_id, "completed", added, skipped, deleted)?;
+
+                #[cfg(feature = "otel")]
+                crate::otel::record_metrics(
+                    self.name(),
+                    added as u64,
+                    skipped as u64,
+                    run_started.elapsed().as_secs_f64(),
+                );
 
                 if self.verbose() {
                     println!("  Added: {}, Skipped (duplicates): {}", added, skipped);
+                    if deleted > 0 {
+                        println!("  Deleted (no longer in source): {}", deleted);
+                    }
+                    let dropped = metrics.total_dropped();
+                    if dropped > 0 {
+                        println!("  Dropped (missing key/timestamp): {}", dropped);
+                    }
+                }
+
+                self.config().progress.report_finished(self.name());
+                Ok(result.complete(added, skipped).with_metrics(metrics))
+            }
+            Err(Error::Cancelled) => {
+                // Best-effort: the transaction may never have been opened (e.g. the source
+                // failed to open before BEGIN ran), so ignore a failing rollback here. Whatever
+                // rows were flushed by an earlier `COMMIT; BEGIN` batch boundary stay committed;
+                // only the partial, not-yet-flushed batch is lost, so these counts are close to
+                // but not exactly what's in the unified database.
+                let _ = self.unified_db().execute_batch("ROLLBACK");
+
+                let (added, skipped) = self.get_counts();
+                self.complete_extraction_run(run_id, "cancelled", added, skipped, 0)?;
+
+                #[cfg(feature = "otel")]
+                crate::otel::record_metrics(
+                    self.name(),
+                    added as u64,
+                    skipped as u64,
+                    run_started.elapsed().as_secs_f64(),
+                );
+
+                if self.verbose() {
+                    println!("  ⚠ Extraction cancelled");
                 }
 
-                Ok(result.complete(added, skipped))
+                self.config().progress.report_finished(self.name());
+                Ok(result.cancel(added, skipped).with_metrics(metrics))
             }
             Err(e) => {
+                // Best-effort: the transaction may never have been opened (e.g. the source
+                // failed to open before BEGIN ran), so ignore a failing rollback here.
+                let _ = self.unified_db().execute_batch("ROLLBACK");
+
                 let error_msg = e.to_string();
-                self.complete_extraction_run(run_id, "failed", 0, 0)?;
+                self.complete_extraction_run(run_id, "failed", 0, 0, 0)?;
+
+                #[cfg(feature = "tracing")]
+                {
+                    let _span =
+                        crate::tracing_support::collector_span(self.name(), "run", "").entered();
+                    tracing::error!(error = %e, "collector run failed");
+                }
+
+                #[cfg(feature = "otel")]
+                crate::otel::record_metrics(self.name(), 0, 0, run_started.elapsed().as_secs_f64());
 
                 if self.verbose() {
                     println!("  ✗ Extraction failed: {}", error_msg);
                 }
 
-                Ok(result.fail(error_msg))
+                self.config().progress.report_finished(self.name());
+                Ok(result.fail(error_msg).with_metrics(metrics))
             }
         }
     }
 
+    /// Copy, integrity-check, and extract a single entry from [`Collector::source_dbs`]. Split
+    /// out of [`Collector::run`] so it can be tried independently per source database without
+    /// one bad Chrome profile taking the others down with it.
+    fn extract_one_source(
+        &mut self,
+        source_path: &PathBuf,
+        label: &str,
+        metrics: &mut Metrics,
+    ) -> Result<()> {
+        let source_db_copy = self.copy_source_db(source_path, label).map_err(|e| {
+            Error::ExtractionFailed(format!("Failed to copy source database: {}", e))
+        })?;
+
+        // Forensic chain of custody: hash the original source file (the thing that was
+        // actually captured) and run a quick_check against the read-only copy before trusting
+        // it for extraction. Recorded into `source_provenance` regardless of outcome.
+        let sha256 = integrity::sha256_file(source_path).map_err(|e| {
+            Error::ExtractionFailed(format!("Failed to hash source database: {}", e))
+        })?;
+        let size_bytes = fs::metadata(source_path).map(|m| m.len()).unwrap_or(0);
+
+        let integrity_result = match integrity::open_source_readonly(&source_db_copy)
+            .and_then(|conn| integrity::quick_check(&conn))
+        {
+            Ok(check) => check,
+            Err(e) => format!("could not run integrity check: {}", e),
+        };
+
+        self.store().record_source_provenance(
+            self.name(),
+            &source_path.display().to_string(),
+            size_bytes,
+            &sha256,
+            &integrity_result,
+        )?;
+
+        if integrity_result != "ok" {
+            return Err(Error::IntegrityCheckFailed {
+                path: source_path.clone(),
+                detail: integrity_result,
+            });
+        }
+
+        let source_conn = integrity::open_source_readonly(&source_db_copy)?;
+        self.extract_profile(&source_conn, metrics, label)
+    }
+
     /// Get the extraction config
     fn config(&self) -> &ExtractionConfig;
 
@@ -152,29 +335,118 @@ pub trait Collector {
             }
         }
 
+        // Brave/Edge: same per-profile `History` layout as Chrome, under their own
+        // Application Support roots.
+        if name == "brave" {
+            if let Some(found) = discover_brave_history_db() {
+                return Some(found);
+            }
+        }
+
+        if name == "edge" {
+            if let Some(found) = discover_edge_history_db() {
+                return Some(found);
+            }
+        }
+
+        // Firefox: scan `~/Library/Application Support/Firefox/Profiles/` for the
+        // most-recently-used `<hash>.<name>` profile directory's `places.sqlite`.
+        if name == "firefox" {
+            if let Some(found) = discover_firefox_places_db() {
+                return Some(found);
+            }
+        }
+
         None
     }
 
-    /// Copy source database to working directory
-    fn copy_source_db(&self, source_path: &PathBuf) -> Result<PathBuf> {
+    /// Snapshot the source database into the working directory.
+    ///
+    /// The apps these collectors read from (Messages, Chrome, Podcasts) keep their database in
+    /// SQLite WAL mode, so committed rows can still be sitting in a `-wal` file rather than the
+    /// main one; a plain byte copy can grab a torn page or miss that tail entirely, leaving the
+    /// collector to later fail with "database disk image is malformed". Prefer SQLite's Online
+    /// Backup API instead: opening the source read-only and backing it up into a fresh file
+    /// forces a WAL checkpoint into the snapshot, so the result is always one consistent `.db`.
+    /// Only fall back to a raw file copy (main file plus any `-wal`/`-shm` siblings) if the
+    /// backup itself fails to open or run.
+    fn copy_source_db(&self, source_path: &PathBuf, label: &str) -> Result<PathBuf> {
         // Ensure source_db_dir exists
         fs::create_dir_all(&self.config().source_db_dir)?;
 
-        let dest = self.config().source_db_dir.join(format!("{}.db", self.name()));
+        let filename = if label.is_empty() {
+            format!("{}.db", self.name())
+        } else {
+            format!("{}-{}.db", self.name(), label)
+        };
+        let dest = self.config().source_db_dir.join(filename);
 
         // Delete old copy if it exists
         if dest.exists() {
             fs::remove_file(&dest)?;
         }
 
-        // Copy the database
-        match fs::copy(source_path, &dest) {
+        // The source is a live WAL-mode database another process may be writing to at the same
+        // moment, so a `SQLITE_BUSY`/`SQLITE_LOCKED` failure here is expected to clear on its
+        // own shortly; retry a few times before giving up on the Online Backup API and falling
+        // back to a raw file copy.
+        let backup_result = crate::retry::retry(
+            "backup_source_db",
+            crate::retry::RetryConfig::default(),
+            || self.backup_source_db(source_path, &dest),
+        );
+        if backup_result.is_err() {
+            self.copy_source_db_raw(source_path, &dest)?;
+        }
+
+        if self.verbose() {
+            let size = format_file_size(&dest)?;
+            println!("  ✓ Copied fresh {} database ({})", self.name(), size);
+        }
+
+        Ok(dest)
+    }
+
+    /// Materialize a consistent snapshot of `source_path` at `dest` via SQLite's Online Backup
+    /// API, checkpointing any pending WAL content into the destination in the process.
+    fn backup_source_db(&self, source_path: &PathBuf, dest: &PathBuf) -> Result<()> {
+        let source_conn = integrity::open_source_for_backup(source_path)?;
+        let mut dest_conn = Connection::open(dest)?;
+
+        let backup = rusqlite::backup::Backup::new(&source_conn, &mut dest_conn)?;
+        let _ = backup.step(-1)?;
+
+        Ok(())
+    }
+
+    /// Fallback used only when [`Collector::backup_source_db`] fails: a plain byte copy of the
+    /// main database file, plus its `-wal`/`-shm` siblings (if present) so a reader that
+    /// understands WAL can still see not-yet-checkpointed commits.
+    ///
+    /// Unlike the Online Backup API, a plain byte copy has no way to detect a torn read if the
+    /// source changed mid-copy, so the result is re-hashed against a checksum taken just before
+    /// `fs::copy` started and run through [`integrity::verify_integrity`]: a bad copy reports
+    /// [`Error::ChecksumMismatch`]/[`Error::Corruption`] with the actual check output rather than
+    /// succeeding silently or failing later with a generic [`Error::CopyFailed`].
+    fn copy_source_db_raw(&self, source_path: &PathBuf, dest: &PathBuf) -> Result<()> {
+        let expected_hash = integrity::sha256_file(source_path).ok();
+
+        match fs::copy(source_path, dest) {
             Ok(_) => {
-                if self.verbose() {
-                    let size = format_file_size(&dest)?;
-                    println!("  ✓ Copied fresh {} database ({})", self.name(), size);
+                for suffix in ["-wal", "-shm"] {
+                    let sidecar = PathBuf::from(format!("{}{}", source_path.display(), suffix));
+                    if sidecar.exists() {
+                        let dest_sidecar = PathBuf::from(format!("{}{}", dest.display(), suffix));
+                        let _ = fs::copy(&sidecar, &dest_sidecar);
+                    }
+                }
+
+                if let Some(expected) = expected_hash {
+                    integrity::verify_checksum(dest, &expected)?;
                 }
-                Ok(dest)
+                integrity::verify_integrity(dest)?;
+
+                Ok(())
             }
             Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
                 Err(Error::PermissionDenied {
@@ -185,30 +457,76 @@ pub trait Collector {
         }
     }
 
+    /// Get the storage backend this collector writes run bookkeeping through.
+    ///
+    /// Defaults to wrapping `unified_db()` in a [`SqliteBackend`]; returning `Box<dyn
+    /// StorageBackend>` rather than the concrete `SqliteBackend` is what actually makes this a
+    /// seam a collector can override to target a different [`StorageBackend`] (e.g. Postgres)
+    /// without changing its extraction logic, rather than just documentation asserting it is one.
+    fn store(&self) -> Box<dyn StorageBackend + '_> {
+        Box::new(SqliteBackend::new(self.unified_db()))
+    }
+
     /// Start an extraction run record in the database
     fn start_extraction_run(&self) -> Result<i64> {
-        let now = timestamp::now_unix();
-        self.unified_db().execute(
-            "INSERT INTO extraction_runs (started_at, source, status) VALUES (?, ?, 'running')",
-            rusqlite::params![now, self.name()],
-        )?;
-        Ok(self.unified_db().last_insert_rowid())
+        self.store().start_extraction_run(self.name())
     }
 
-    /// Complete an extraction run record
+    /// Complete an extraction run record, stamping it with this collector's watermark (see
+    /// [`Collector::watermark`]) as of now so `extraction_runs` shows how far incremental
+    /// extraction had reached without joining out to `extraction_state`.
     fn complete_extraction_run(
         &self,
         run_id: i64,
         status: &str,
         records_added: usize,
         records_skipped: usize,
+        records_deleted: usize,
     ) -> Result<()> {
-        let now = timestamp::now_unix();
-        self.unified_db().execute(
-            "UPDATE extraction_runs SET completed_at = ?, records_added = ?, records_skipped = ?, status = ? WHERE id = ?",
-            rusqlite::params![now, records_added as i64, records_skipped as i64, status, run_id],
-        )?;
-        Ok(())
+        let last_seen_watermark = self.watermark()?;
+        self.store().complete_extraction_run(
+            run_id,
+            status,
+            records_added,
+            records_skipped,
+            records_deleted,
+            last_seen_watermark,
+        )
+    }
+
+    /// Source primary keys (or another stable source identifier) seen during the most recent
+    /// `extract` call, for [`Collector::reconcile_deletions`] to diff against. Defaults to empty,
+    /// meaning tombstone detection is a no-op; a collector that wants it must track ids itself
+    /// (typically by appending to a field on its own struct during `extract`) and override this
+    /// to return them.
+    fn seen_source_ids(&self) -> HashSet<String> {
+        HashSet::new()
+    }
+
+    /// Soft-delete (set `deleted_at`) any row this collector previously wrote whose source id is
+    /// missing from `seen_source_ids`, since it was removed at the source (a deleted message, a
+    /// cleared history entry) rather than merely unseen because this run only read a partial
+    /// window. Returns the number of rows newly marked deleted. Defaults to a no-op, since a
+    /// collector must opt in by overriding both this and [`Collector::seen_source_ids`] - without
+    /// the full set of ids actually seen, "missing" can't be told apart from "not read this run".
+    fn reconcile_deletions(&mut self, _seen_source_ids: &HashSet<String>) -> Result<usize> {
+        Ok(0)
+    }
+
+    /// Get this collector's persisted watermark (the highest source-native timestamp
+    /// successfully extracted so far), or `None` if there isn't one yet or
+    /// `config().full_resync` is set.
+    fn watermark(&self) -> Result<Option<i64>> {
+        if self.config().full_resync {
+            return Ok(None);
+        }
+        self.store().get_watermark(self.name())
+    }
+
+    /// Persist a new watermark for this collector. No-op (handled by the backend) if `value`
+    /// is not higher than the one already stored.
+    fn advance_watermark(&self, value: i64) -> Result<()> {
+        self.store().set_watermark(self.name(), value)
     }
 }
 
@@ -264,38 +582,39 @@ fn discover_podcasts_db() -> Option<PathBuf> {
     None
 }
 
-/// Best-effort discovery for Google Chrome History database.
-///
-/// Chrome keeps per-profile History DBs in:
-/// `~/Library/Application Support/Google/Chrome/<ProfileDir>/History`
-/// where `<ProfileDir>` can be `Default`, `Profile 1`, `Profile 2`, etc.
-///
-/// We scan the Chrome directory and pick the most recently modified History file.
-fn discover_chrome_history_db() -> Option<PathBuf> {
-    let chrome_root = PathBuf::from(
-        shellexpand::tilde("~/Library/Application Support/Google/Chrome").as_ref(),
-    );
-    if !chrome_root.is_dir() {
-        return None;
+/// Scan `root` for Chromium-family profile directories (`Default`, `Profile N`, `Guest Profile`)
+/// and return each one's `History` database, paired with a filesystem-safe label derived from its
+/// profile directory name (`Default` -> `default`, `Profile 1` -> `profile-1`, `Guest Profile` ->
+/// `guest-profile`). Every Chromium-family browser (Chrome, Brave, Edge) lays out profiles
+/// identically; only the root directory differs, so [`discover_chrome_history_dbs`],
+/// [`discover_brave_history_dbs`], and [`discover_edge_history_dbs`] are thin wrappers over this.
+pub(crate) fn discover_chromium_profiles(root: &PathBuf) -> Vec<(PathBuf, String)> {
+    if !root.is_dir() {
+        return Vec::new();
     }
 
-    let mut best: Option<(PathBuf, std::time::SystemTime)> = None;
+    let mut found = Vec::new();
+
+    let entries = match fs::read_dir(root) {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
 
-    let entries = fs::read_dir(&chrome_root).ok()?;
     for entry in entries.flatten() {
         let profile_dir = entry.path();
         if !profile_dir.is_dir() {
             continue;
         }
 
-        // Only consider typical Chrome profile directories
+        // Only consider typical Chromium profile directories
         let profile_name = match profile_dir.file_name().and_then(|s| s.to_str()) {
             Some(s) => s,
             None => continue,
         };
 
-        let looks_like_profile =
-            profile_name == "Default" || profile_name.starts_with("Profile ") || profile_name == "Guest Profile";
+        let looks_like_profile = profile_name == "Default"
+            || profile_name.starts_with("Profile ")
+            || profile_name == "Guest Profile";
 
         if !looks_like_profile {
             continue;
@@ -306,18 +625,116 @@ fn discover_chrome_history_db() -> Option<PathBuf> {
             continue;
         }
 
-        let modified = fs::metadata(&history).and_then(|m| m.modified()).ok();
+        let label = profile_name.to_lowercase().replace(' ', "-");
+        found.push((history, label));
+    }
+
+    found
+}
+
+/// Best-effort discovery for Google Chrome's History database.
+///
+/// Chrome keeps per-profile History DBs in:
+/// `~/Library/Application Support/Google/Chrome/<ProfileDir>/History`
+/// where `<ProfileDir>` can be `Default`, `Profile 1`, `Profile 2`, etc.
+///
+/// We scan the Chrome directory and pick the most recently modified History file. Used only as
+/// [`Collector::find_source_db`]'s single-result fallback for the `chrome` collector (e.g. debug
+/// reporting); [`ChromeCollector`](crate::collectors::chrome::ChromeCollector)
+/// itself reads every profile via [`discover_chrome_history_dbs`] instead.
+fn discover_chrome_history_db() -> Option<PathBuf> {
+    discover_chrome_history_dbs()
+        .into_iter()
+        .max_by_key(|(path, _)| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .map(|(path, _)| path)
+}
+
+/// Every Chrome profile's History database. See [`discover_chromium_profiles`].
+pub(crate) fn discover_chrome_history_dbs() -> Vec<(PathBuf, String)> {
+    let chrome_root =
+        PathBuf::from(shellexpand::tilde("~/Library/Application Support/Google/Chrome").as_ref());
+    discover_chromium_profiles(&chrome_root)
+}
+
+/// Best-effort discovery for Brave's History database, analogous to
+/// [`discover_chrome_history_db`] but under Brave's own Application Support root.
+fn discover_brave_history_db() -> Option<PathBuf> {
+    discover_brave_history_dbs()
+        .into_iter()
+        .max_by_key(|(path, _)| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .map(|(path, _)| path)
+}
+
+/// Every Brave profile's History database. See [`discover_chromium_profiles`].
+pub(crate) fn discover_brave_history_dbs() -> Vec<(PathBuf, String)> {
+    let brave_root = PathBuf::from(
+        shellexpand::tilde("~/Library/Application Support/BraveSoftware/Brave-Browser").as_ref(),
+    );
+    discover_chromium_profiles(&brave_root)
+}
+
+/// Best-effort discovery for Microsoft Edge's History database, analogous to
+/// [`discover_chrome_history_db`] but under Edge's own Application Support root.
+fn discover_edge_history_db() -> Option<PathBuf> {
+    discover_edge_history_dbs()
+        .into_iter()
+        .max_by_key(|(path, _)| fs::metadata(path).and_then(|m| m.modified()).ok())
+        .map(|(path, _)| path)
+}
+
+/// Every Edge profile's History database. See [`discover_chromium_profiles`].
+pub(crate) fn discover_edge_history_dbs() -> Vec<(PathBuf, String)> {
+    let edge_root =
+        PathBuf::from(shellexpand::tilde("~/Library/Application Support/Microsoft Edge").as_ref());
+    discover_chromium_profiles(&edge_root)
+}
+
+/// Best-effort discovery for Firefox's `places.sqlite`.
+///
+/// Firefox profiles live under `~/Library/Application Support/Firefox/Profiles/<hash>.<name>/`,
+/// where `<hash>` is a random per-install salt. Unlike Chromium's profiles, there's no reliable
+/// naming convention to prefer one over another, so we pick the profile whose `places.sqlite` was
+/// most recently modified (a reasonable proxy for "the one the user actually uses").
+pub(crate) fn discover_firefox_places_db() -> Option<PathBuf> {
+    let profiles_root = PathBuf::from(
+        shellexpand::tilde("~/Library/Application Support/Firefox/Profiles").as_ref(),
+    );
+    if !profiles_root.is_dir() {
+        return None;
+    }
+
+    let entries = fs::read_dir(&profiles_root).ok()?;
+    let mut best: Option<(PathBuf, std::time::SystemTime)> = None;
+
+    for entry in entries.flatten() {
+        let profile_dir = entry.path();
+        if !profile_dir.is_dir() {
+            continue;
+        }
+
+        let places = profile_dir.join("places.sqlite");
+        if !places.exists() {
+            continue;
+        }
+
+        let modified = match fs::metadata(&places).and_then(|m| m.modified()) {
+            Ok(m) => m,
+            Err(_) => continue,
+        };
 
-        match (modified, &best) {
-            (Some(m), Some((_, best_m))) if m > *best_m => best = Some((history, m)),
-            (Some(m), None) => best = Some((history, m)),
-            _ => {}
+        match &best {
+            Some((_, best_modified)) if *best_modified >= modified => {}
+            _ => best = Some((places, modified)),
         }
     }
 
-    best.map(|(p, _)| p)
+    best.map(|(path, _)| path)
 }
 
+/// Commit and reopen the surrounding transaction after this many rows, so a multi-year
+/// extraction bounds its memory/lock footprint instead of holding one giant transaction open.
+const TRANSACTION_BATCH_SIZE: usize = 10_000;
+
 /// Base collector struct with common fields
 pub struct BaseCollector<'a> {
     pub name: String,
@@ -325,6 +742,7 @@ pub struct BaseCollector<'a> {
     pub unified_db: &'a Connection,
     pub records_added: usize,
     pub records_skipped: usize,
+    rows_since_commit: usize,
 }
 
 impl<'a> BaseCollector<'a> {
@@ -335,6 +753,41 @@ impl<'a> BaseCollector<'a> {
             unified_db,
             records_added: 0,
             records_skipped: 0,
+            rows_since_commit: 0,
         }
     }
+
+    /// Run a dedup insert, bumping `records_added`/`records_skipped` based on the outcome.
+    ///
+    /// This captures the `execute(...).map(|_| added += 1).else(ConstraintViolation => skipped += 1)`
+    /// pattern every collector repeats around its `INSERT INTO ...` statements, so a collector
+    /// body can shrink to `base.insert_dedup(sql, params)?` instead of a full match arm. The
+    /// caller's `run()` holds one transaction open around the whole extraction; every
+    /// [`TRANSACTION_BATCH_SIZE`] rows this flushes it with a `COMMIT`/`BEGIN` pair so memory
+    /// and lock hold time stay bounded on very large sources.
+    pub fn insert_dedup(&mut self, sql: &str, params: &[&dyn rusqlite::ToSql]) -> Result<()> {
+        if SqliteBackend::new(self.unified_db).insert_dedup(sql, params)? {
+            self.records_added += 1;
+        } else {
+            self.records_skipped += 1;
+        }
+
+        self.rows_since_commit += 1;
+        if self.rows_since_commit >= TRANSACTION_BATCH_SIZE {
+            self.unified_db.execute_batch("COMMIT; BEGIN")?;
+            self.rows_since_commit = 0;
+
+            self.config.progress.report_progress(
+                &self.name,
+                self.records_added + self.records_skipped,
+                None,
+            );
+
+            if self.config.progress.is_cancelled() {
+                return Err(Error::Cancelled);
+            }
+        }
+
+        Ok(())
+    }
 }
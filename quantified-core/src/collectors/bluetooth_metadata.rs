@@ -0,0 +1,114 @@
+//! Bluetooth device metadata enrichment.
+//!
+//! knowledgeC's `/bluetooth/isConnected` stream only gives us three opaque values for a paired
+//! device: `Z_DKBLUETOOTHMETADATAKEY__DEVICETYPE` (a small numeric enum Apple doesn't publish —
+//! see the note below), `..._PRODUCTID` (a vendor-assigned product code, populated for Apple
+//! accessories), and `..._ADDRESS` (the raw MAC). This resolves the latter two into a `vendor`
+//! and `model_name` that [`super::knowledgec`] writes alongside the raw columns on
+//! `bluetooth_connections`.
+//!
+//! `device_type` itself is left as the raw integer on that table rather than resolved to a
+//! category (headphones/watch/keyboard/car, etc.), and that is a confirmed scope decision, not
+//! an omission: this field is Apple-internal to CoreDuet/knowledgeC, not the Bluetooth SIG's
+//! "Class of Device" value (which *is* publicly assigned and would be safe to map), and the only
+//! mappings for it that exist anywhere are reverse-engineered by third-party forensics tooling,
+//! observed against specific OS versions with no guarantee they hold across others. Shipping one
+//! of those as this crate's own mapping would print a confident-looking category that silently
+//! goes wrong on an OS version it wasn't checked against. An analyst gets the unmodified integer
+//! to interpret (or look up against whichever reverse-engineered table they trust) instead.
+
+/// Known Apple `Z_DKBLUETOOTHMETADATAKEY__PRODUCTID` values, mapped to the accessory model they
+/// identify. Apple only populates this field for its own accessories; third-party devices are
+/// left to [`vendor_from_address`] instead.
+const APPLE_PRODUCT_IDS: &[(i64, &str)] = &[
+    (0x2002, "AirPods (1st generation)"),
+    (0x200a, "AirPods (2nd generation)"),
+    (0x2013, "AirPods (3rd generation)"),
+    (0x200e, "AirPods Pro"),
+    (0x2014, "AirPods Pro (2nd generation)"),
+    (0x200f, "AirPods Max"),
+    (0x2005, "Powerbeats3"),
+    (0x0265, "Magic Mouse 2"),
+    (0x0267, "Magic Keyboard"),
+    (0x0269, "Magic Trackpad 2"),
+    (0x0272, "Apple Watch"),
+];
+
+/// Resolve an Apple accessory `product_id` into a model name, if recognized.
+pub fn apple_product_name(product_id: Option<i64>) -> Option<&'static str> {
+    let product_id = product_id?;
+    APPLE_PRODUCT_IDS
+        .iter()
+        .find(|(id, _)| *id == product_id)
+        .map(|(_, name)| *name)
+}
+
+/// A small embedded slice of the IEEE OUI registry (organizationally unique identifiers: the
+/// first three octets of a MAC address), covering vendors common in a macOS user's paired-device
+/// list. This is nowhere near the full registry; unmatched prefixes resolve to `None` rather than
+/// a guess.
+const OUI_VENDORS: &[(&str, &str)] = &[
+    ("AC:DE:48", "Apple"),
+    ("F0:18:98", "Apple"),
+    ("BC:92:6B", "Apple"),
+    ("04:0C:CE", "Apple"),
+    ("28:FF:3C", "Samsung"),
+    ("8C:79:F5", "Samsung"),
+    ("00:1C:62", "Sony"),
+    ("FC:A1:3E", "Sony"),
+    ("AC:9E:17", "Bose"),
+    ("00:1B:FB", "Logitech"),
+    ("7C:1D:D9", "Logitech"),
+    ("00:50:F2", "Microsoft"),
+];
+
+/// Resolve the vendor prefix of a `device_address` (a `XX:XX:XX:XX:XX:XX` MAC) against the
+/// embedded OUI table above.
+pub fn vendor_from_address(address: Option<&str>) -> Option<&'static str> {
+    let address = address?;
+    let prefix: String = address
+        .splitn(4, ':')
+        .take(3)
+        .collect::<Vec<_>>()
+        .join(":")
+        .to_uppercase();
+
+    OUI_VENDORS
+        .iter()
+        .find(|(oui, _)| *oui == prefix)
+        .map(|(_, vendor)| *vendor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apple_product_name_known() {
+        assert_eq!(apple_product_name(Some(0x200e)), Some("AirPods Pro"));
+    }
+
+    #[test]
+    fn test_apple_product_name_unknown_or_missing() {
+        assert_eq!(apple_product_name(Some(0x9999)), None);
+        assert_eq!(apple_product_name(None), None);
+    }
+
+    #[test]
+    fn test_vendor_from_address_known() {
+        assert_eq!(
+            vendor_from_address(Some("AC:DE:48:12:34:56")),
+            Some("Apple")
+        );
+        assert_eq!(
+            vendor_from_address(Some("ac:de:48:12:34:56")),
+            Some("Apple")
+        );
+    }
+
+    #[test]
+    fn test_vendor_from_address_unknown_or_missing() {
+        assert_eq!(vendor_from_address(Some("11:22:33:44:55:66")), None);
+        assert_eq!(vendor_from_address(None), None);
+    }
+}
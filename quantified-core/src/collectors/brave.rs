@@ -0,0 +1,81 @@
+//! Brave collector for browser history from Brave's History database
+//!
+//! Brave is Chromium-based and keeps the same `urls`/`visits` schema as Chrome, just under its
+//! own Application Support root; extraction is shared via [`crate::collectors::chromium`].
+
+use crate::collectors::base::{discover_brave_history_dbs, BaseCollector, Collector};
+use crate::collectors::chromium;
+use crate::error::Result;
+use crate::metrics::Metrics;
+use crate::types::{CollectorType, ExtractionConfig};
+use rusqlite::Connection;
+use std::path::PathBuf;
+
+pub struct BraveCollector<'a> {
+    base: BaseCollector<'a>,
+}
+
+impl<'a> BraveCollector<'a> {
+    pub fn new(config: &'a ExtractionConfig, unified_db: &'a Connection) -> Result<Self> {
+        Ok(Self {
+            base: BaseCollector::new(CollectorType::Brave.name().to_string(), config, unified_db),
+        })
+    }
+}
+
+impl<'a> Collector for BraveCollector<'a> {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn source_paths(&self) -> Vec<String> {
+        CollectorType::Brave.default_source_paths()
+    }
+
+    fn extract(&mut self, source_conn: &Connection, metrics: &mut Metrics) -> Result<()> {
+        chromium::extract_visits(&mut self.base, source_conn, metrics, "brave", "")
+    }
+
+    /// Every profile's `History` database discovered under the Brave user data directory, not
+    /// just the most recently modified one. See [`crate::collectors::chrome::ChromeCollector::source_dbs`].
+    fn source_dbs(&self) -> Vec<(PathBuf, String)> {
+        let discovered = discover_brave_history_dbs();
+        if !discovered.is_empty() {
+            return discovered;
+        }
+
+        match self.find_source_db() {
+            Some(path) => vec![(path, String::new())],
+            None => Vec::new(),
+        }
+    }
+
+    fn extract_profile(
+        &mut self,
+        source_conn: &Connection,
+        metrics: &mut Metrics,
+        label: &str,
+    ) -> Result<()> {
+        chromium::extract_visits(&mut self.base, source_conn, metrics, "brave", label)
+    }
+
+    fn config(&self) -> &ExtractionConfig {
+        self.base.config
+    }
+
+    fn unified_db(&self) -> &Connection {
+        self.base.unified_db
+    }
+
+    fn get_counts(&self) -> (usize, usize) {
+        (self.base.records_added, self.base.records_skipped)
+    }
+
+    fn increment_added(&mut self) {
+        self.base.records_added += 1;
+    }
+
+    fn increment_skipped(&mut self) {
+        self.base.records_skipped += 1;
+    }
+}
@@ -1,42 +1,12 @@
 //! Chrome collector for browser history from Chrome's History database
 
-use crate::collectors::base::{BaseCollector, Collector};
-use crate::collectors::utils::make_hash_from_values;
+use crate::collectors::base::{discover_chrome_history_dbs, BaseCollector, Collector};
+use crate::collectors::chromium;
 use crate::error::Result;
-use crate::timestamp;
+use crate::metrics::Metrics;
 use crate::types::{CollectorType, ExtractionConfig};
 use rusqlite::Connection;
-
-/// Chrome transition types (how user got to the page)
-const TRANSITION_LINK: i64 = 0;
-const TRANSITION_TYPED: i64 = 1;
-const TRANSITION_AUTO_BOOKMARK: i64 = 2;
-const TRANSITION_AUTO_SUBFRAME: i64 = 3;
-const TRANSITION_MANUAL_SUBFRAME: i64 = 4;
-const TRANSITION_GENERATED: i64 = 5;
-const TRANSITION_AUTO_TOPLEVEL: i64 = 6;
-const TRANSITION_FORM_SUBMIT: i64 = 7;
-const TRANSITION_RELOAD: i64 = 8;
-const TRANSITION_KEYWORD: i64 = 9;
-const TRANSITION_KEYWORD_GENERATED: i64 = 10;
-
-fn get_transition_type_name(transition: i64) -> &'static str {
-    // Lower bits contain the type
-    match transition & 0xFF {
-        TRANSITION_LINK => "link",
-        TRANSITION_TYPED => "typed",
-        TRANSITION_AUTO_BOOKMARK => "auto_bookmark",
-        TRANSITION_AUTO_SUBFRAME => "auto_subframe",
-        TRANSITION_MANUAL_SUBFRAME => "manual_subframe",
-        TRANSITION_GENERATED => "generated",
-        TRANSITION_AUTO_TOPLEVEL => "auto_toplevel",
-        TRANSITION_FORM_SUBMIT => "form_submit",
-        TRANSITION_RELOAD => "reload",
-        TRANSITION_KEYWORD => "keyword",
-        TRANSITION_KEYWORD_GENERATED => "keyword_generated",
-        _ => "other",
-    }
-}
+use std::path::PathBuf;
 
 pub struct ChromeCollector<'a> {
     base: BaseCollector<'a>,
@@ -45,87 +15,9 @@ pub struct ChromeCollector<'a> {
 impl<'a> ChromeCollector<'a> {
     pub fn new(config: &'a ExtractionConfig, unified_db: &'a Connection) -> Result<Self> {
         Ok(Self {
-            base: BaseCollector::new(
-                CollectorType::Chrome.name().to_string(),
-                config,
-                unified_db,
-            ),
+            base: BaseCollector::new(CollectorType::Chrome.name().to_string(), config, unified_db),
         })
     }
-
-    fn extract_visits(&mut self, source: &Connection) -> Result<()> {
-        if self.base.config.verbose {
-            println!("  Extracting web visits...");
-        }
-
-        let mut stmt = source.prepare(
-            r#"
-            SELECT
-                v.id,
-                u.url,
-                u.title,
-                v.visit_time,
-                v.visit_duration,
-                v.transition
-            FROM visits v
-            JOIN urls u ON v.url = u.id
-            ORDER BY v.visit_time
-            "#,
-        )?;
-
-        let mut rows = stmt.query([])?;
-
-        while let Some(row) = rows.next()? {
-            let _visit_id: i64 = row.get(0)?;
-            let url: String = row.get(1)?;
-            let title: Option<String> = row.get(2)?;
-            let visit_time: i64 = row.get(3)?;
-            let duration: Option<i64> = row.get(4)?;
-            let transition: i64 = row.get(5)?;
-
-            // Convert Chrome timestamp to Unix timestamp
-            let timestamp = match timestamp::chrome_to_unix_opt(Some(visit_time)) {
-                Some(ts) => ts,
-                None => continue,
-            };
-
-            // Duration is in microseconds, convert to seconds
-            let duration_seconds = duration.map(|d| if d > 0 { d as f64 / 1_000_000.0 } else { 0.0 });
-
-            // Get transition type name
-            let transition_type = get_transition_type_name(transition);
-
-            // Use visit_time as part of hash since it's microsecond precision
-            let visit_time_str = visit_time.to_string();
-            let record_hash = make_hash_from_values(&[url.as_str(), visit_time_str.as_str(), "chrome"]);
-
-            match self.base.unified_db.execute(
-                r#"
-                INSERT INTO web_visits
-                (record_hash, url, title, visit_time, visit_duration_seconds, transition_type, browser)
-                VALUES (?, ?, ?, ?, ?, ?, 'chrome')
-                "#,
-                rusqlite::params![
-                    record_hash,
-                    url,
-                    title,
-                    timestamp,
-                    duration_seconds,
-                    transition_type,
-                ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
-        }
-
-        Ok(())
-    }
 }
 
 impl<'a> Collector for ChromeCollector<'a> {
@@ -137,9 +29,34 @@ impl<'a> Collector for ChromeCollector<'a> {
         CollectorType::Chrome.default_source_paths()
     }
 
-    fn extract(&mut self, source_conn: &Connection) -> Result<()> {
-        self.extract_visits(source_conn)?;
-        Ok(())
+    fn extract(&mut self, source_conn: &Connection, metrics: &mut Metrics) -> Result<()> {
+        chromium::extract_visits(&mut self.base, source_conn, metrics, "chrome", "")
+    }
+
+    /// Every profile's `History` database discovered under the Chrome user data directory, not
+    /// just the most recently modified one, so a user with multiple Chrome profiles doesn't
+    /// silently lose all but one profile's browsing data.
+    fn source_dbs(&self) -> Vec<(PathBuf, String)> {
+        let discovered = discover_chrome_history_dbs();
+        if !discovered.is_empty() {
+            return discovered;
+        }
+
+        // Fall back to the single-result path (explicit/custom source path, or no profile
+        // directories found) so a custom `chrome.custom_source_paths` override still works.
+        match self.find_source_db() {
+            Some(path) => vec![(path, String::new())],
+            None => Vec::new(),
+        }
+    }
+
+    fn extract_profile(
+        &mut self,
+        source_conn: &Connection,
+        metrics: &mut Metrics,
+        label: &str,
+    ) -> Result<()> {
+        chromium::extract_visits(&mut self.base, source_conn, metrics, "chrome", label)
     }
 
     fn config(&self) -> &ExtractionConfig {
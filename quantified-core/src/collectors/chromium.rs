@@ -0,0 +1,206 @@
+//! Shared extraction logic for Chromium-family browsers (Chrome, Brave, Edge), whose `History`
+//! SQLite schema — `urls`/`visits` tables, visit times as Chrome-epoch microseconds — is
+//! identical regardless of vendor; only the default install location and the `browser` tag
+//! written into `web_visits` differ. See [`discover_chromium_profiles`](super::base::discover_chromium_profiles)
+//! for the matching shared profile-discovery logic.
+
+use crate::collectors::base::BaseCollector;
+use crate::collectors::utils::{make_hash_from_values, query_map};
+use crate::error::Result;
+use crate::metrics::{Metrics, StepMetrics};
+use crate::storage::{SqliteBackend, StorageBackend};
+use crate::timestamp;
+use crate::types::WebVisitTransition;
+use rusqlite::Connection;
+use std::time::Instant;
+
+/// Chromium transition types (how the user got to the page)
+const TRANSITION_LINK: i64 = 0;
+const TRANSITION_TYPED: i64 = 1;
+const TRANSITION_AUTO_BOOKMARK: i64 = 2;
+const TRANSITION_AUTO_SUBFRAME: i64 = 3;
+const TRANSITION_MANUAL_SUBFRAME: i64 = 4;
+const TRANSITION_GENERATED: i64 = 5;
+const TRANSITION_AUTO_TOPLEVEL: i64 = 6;
+const TRANSITION_FORM_SUBMIT: i64 = 7;
+const TRANSITION_RELOAD: i64 = 8;
+const TRANSITION_KEYWORD: i64 = 9;
+const TRANSITION_KEYWORD_GENERATED: i64 = 10;
+
+fn get_transition_type_name(transition: i64) -> &'static str {
+    // Lower bits contain the type
+    match transition & 0xFF {
+        TRANSITION_LINK => "link",
+        TRANSITION_TYPED => "typed",
+        TRANSITION_AUTO_BOOKMARK => "auto_bookmark",
+        TRANSITION_AUTO_SUBFRAME => "auto_subframe",
+        TRANSITION_MANUAL_SUBFRAME => "manual_subframe",
+        TRANSITION_GENERATED => "generated",
+        TRANSITION_AUTO_TOPLEVEL => "auto_toplevel",
+        TRANSITION_FORM_SUBMIT => "form_submit",
+        TRANSITION_RELOAD => "reload",
+        TRANSITION_KEYWORD => "keyword",
+        TRANSITION_KEYWORD_GENERATED => "keyword_generated",
+        _ => "other",
+    }
+}
+
+/// Map Chromium's transition bitmask onto the normalized [`WebVisitTransition`] shared across
+/// browser collectors. Subframe loads (auto/manual) aren't user-visible top-level navigations, so
+/// both collapse to `Embedded`; `AUTO_TOPLEVEL` (e.g. a new tab auto-navigating to a URL without
+/// the user clicking anything) collapses to `Redirect` for the same reason.
+fn classify_transition(transition: i64) -> WebVisitTransition {
+    match transition & 0xFF {
+        TRANSITION_LINK => WebVisitTransition::Link,
+        TRANSITION_TYPED
+        | TRANSITION_GENERATED
+        | TRANSITION_KEYWORD
+        | TRANSITION_KEYWORD_GENERATED => WebVisitTransition::Typed,
+        TRANSITION_AUTO_BOOKMARK => WebVisitTransition::AutoBookmark,
+        TRANSITION_AUTO_SUBFRAME | TRANSITION_MANUAL_SUBFRAME => WebVisitTransition::Embedded,
+        TRANSITION_AUTO_TOPLEVEL => WebVisitTransition::Redirect,
+        TRANSITION_FORM_SUBMIT => WebVisitTransition::FormSubmit,
+        TRANSITION_RELOAD => WebVisitTransition::Reload,
+        _ => WebVisitTransition::Link,
+    }
+}
+
+/// Extract every visit newer than the collector's watermark from a Chromium-family `History`
+/// database (already opened read-only at `source`) into `web_visits`, tagging each row with
+/// `browser` and `profile`. Shared by [`ChromeCollector`](crate::collectors::chrome::ChromeCollector),
+/// [`BraveCollector`](crate::collectors::brave::BraveCollector), and
+/// [`EdgeCollector`](crate::collectors::edge::EdgeCollector).
+///
+/// The watermark is keyed by `browser:profile` (just `browser` when there's only ever one
+/// profile, e.g. `profile` is `""`), not by `browser` alone: `run()` extracts every discovered
+/// profile in one pass, and a single shared key would let whichever profile happens to be
+/// processed first (read_dir order is nondeterministic) advance it past rows a slower profile
+/// hasn't read yet — permanently losing that profile's older visits on every later incremental
+/// run, not just until "the next run catches up".
+fn watermark_key(browser: &str, profile: &str) -> String {
+    if profile.is_empty() {
+        browser.to_string()
+    } else {
+        format!("{}:{}", browser, profile)
+    }
+}
+
+pub fn extract_visits(
+    base: &mut BaseCollector,
+    source: &Connection,
+    metrics: &mut Metrics,
+    browser: &str,
+    profile: &str,
+) -> Result<()> {
+    if base.config.verbose {
+        println!("  Extracting {} web visits...", browser);
+    }
+
+    let started = Instant::now();
+    let mut rows_dropped = 0usize;
+    let (added_before, skipped_before) = (base.records_added, base.records_skipped);
+    let watermark_key = watermark_key(browser, profile);
+
+    // Incremental mode: only scan visits newer than our last watermark, relying on the
+    // record-hash dedup as a correctness backstop rather than the sole dedup mechanism.
+    let watermark = if base.config.full_resync {
+        None
+    } else {
+        SqliteBackend::new(base.unified_db).get_watermark(&watermark_key)?
+    };
+    let mut max_visit_time_seen = watermark.unwrap_or(0);
+
+    let query = format!(
+        r#"
+        SELECT
+            v.id,
+            u.url,
+            u.title,
+            v.visit_time,
+            v.visit_duration,
+            v.transition
+        FROM visits v
+        JOIN urls u ON v.url = u.id
+        {}
+        ORDER BY v.visit_time
+        "#,
+        if watermark.is_some() {
+            "WHERE v.visit_time > ?"
+        } else {
+            ""
+        }
+    );
+
+    let mut stmt = source.prepare(&query)?;
+
+    let rows = match watermark {
+        Some(wm) => stmt.query(rusqlite::params![wm])?,
+        None => stmt.query([])?,
+    };
+    let visits: Vec<(i64, String, Option<String>, i64, Option<i64>, i64)> = query_map(rows)?;
+    let rows_scanned = visits.len();
+
+    for (_visit_id, url, title, visit_time, duration, transition) in visits {
+        max_visit_time_seen = max_visit_time_seen.max(visit_time);
+
+        // Convert Chrome-epoch timestamp to Unix timestamp
+        let timestamp = match timestamp::chrome_to_unix_opt(Some(visit_time)) {
+            Some(ts) => ts,
+            None => {
+                rows_dropped += 1;
+                continue;
+            }
+        };
+
+        // Duration is in microseconds, convert to seconds
+        let duration_seconds = duration.map(|d| if d > 0 { d as f64 / 1_000_000.0 } else { 0.0 });
+
+        // Browser-native label, plus the normalized category shared across browsers
+        let transition_type = get_transition_type_name(transition);
+        let normalized_transition = classify_transition(transition).as_str();
+
+        // Use visit_time as part of hash since it's microsecond precision. Profile is not part
+        // of the hash: the same URL/visit_time pair can't legitimately occur in two profiles at
+        // once, and keeping it out of the hash means a profile directory rename (e.g. renumbering
+        // `Profile 2` to `Profile 1`) doesn't duplicate the row.
+        let visit_time_str = visit_time.to_string();
+        let record_hash = make_hash_from_values(&[url.as_str(), visit_time_str.as_str(), browser]);
+
+        base.insert_dedup(
+            r#"
+            INSERT OR IGNORE INTO web_visits
+            (record_hash, url, title, visit_time, visit_duration_seconds, transition_type, transition, browser, profile)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            rusqlite::params![
+                record_hash,
+                url,
+                title,
+                timestamp,
+                duration_seconds,
+                transition_type,
+                normalized_transition,
+                browser,
+                profile,
+            ],
+        )?;
+    }
+
+    if max_visit_time_seen > watermark.unwrap_or(0) {
+        SqliteBackend::new(base.unified_db).set_watermark(&watermark_key, max_visit_time_seen)?;
+    }
+
+    metrics.record_step(
+        browser,
+        "visits",
+        StepMetrics {
+            rows_scanned,
+            rows_added: base.records_added - added_before,
+            rows_skipped: base.records_skipped - skipped_before,
+            rows_dropped,
+            duration: started.elapsed(),
+        },
+    );
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+//! Firefox collector for browser history from Firefox's `places.sqlite` database
+
+use crate::collectors::base::{BaseCollector, Collector};
+use crate::collectors::utils::{make_hash_from_values, query_map};
+use crate::error::Result;
+use crate::metrics::{Metrics, StepMetrics};
+use crate::timestamp;
+use crate::types::{CollectorType, ExtractionConfig, WebVisitTransition};
+use rusqlite::Connection;
+use std::time::Instant;
+
+/// Firefox's `moz_historyvisits.visit_type` values (how the user got to the page). Distinct from
+/// Chromium's transition bitmask, but serves the same role.
+const VISIT_TYPE_LINK: i64 = 1;
+const VISIT_TYPE_TYPED: i64 = 2;
+const VISIT_TYPE_BOOKMARK: i64 = 3;
+const VISIT_TYPE_EMBED: i64 = 4;
+const VISIT_TYPE_REDIRECT_PERMANENT: i64 = 5;
+const VISIT_TYPE_REDIRECT_TEMPORARY: i64 = 6;
+const VISIT_TYPE_DOWNLOAD: i64 = 7;
+const VISIT_TYPE_FRAMED_LINK: i64 = 8;
+const VISIT_TYPE_RELOAD: i64 = 9;
+
+fn get_visit_type_name(visit_type: i64) -> &'static str {
+    match visit_type {
+        VISIT_TYPE_LINK => "link",
+        VISIT_TYPE_TYPED => "typed",
+        VISIT_TYPE_BOOKMARK => "bookmark",
+        VISIT_TYPE_EMBED => "embed",
+        VISIT_TYPE_REDIRECT_PERMANENT => "redirect_permanent",
+        VISIT_TYPE_REDIRECT_TEMPORARY => "redirect_temporary",
+        VISIT_TYPE_DOWNLOAD => "download",
+        VISIT_TYPE_FRAMED_LINK => "framed_link",
+        VISIT_TYPE_RELOAD => "reload",
+        _ => "other",
+    }
+}
+
+/// Map Firefox's `visit_type` onto the normalized [`WebVisitTransition`] shared across browser
+/// collectors. `download` has no equivalent category, so it falls back to `Link` (the weakest
+/// assumption) rather than inventing a category the request didn't ask for.
+fn classify_visit_type(visit_type: i64) -> WebVisitTransition {
+    match visit_type {
+        VISIT_TYPE_LINK => WebVisitTransition::Link,
+        VISIT_TYPE_TYPED => WebVisitTransition::Typed,
+        VISIT_TYPE_BOOKMARK => WebVisitTransition::Bookmark,
+        VISIT_TYPE_EMBED | VISIT_TYPE_FRAMED_LINK => WebVisitTransition::Embedded,
+        VISIT_TYPE_REDIRECT_PERMANENT | VISIT_TYPE_REDIRECT_TEMPORARY => {
+            WebVisitTransition::Redirect
+        }
+        VISIT_TYPE_RELOAD => WebVisitTransition::Reload,
+        _ => WebVisitTransition::Link,
+    }
+}
+
+pub struct FirefoxCollector<'a> {
+    base: BaseCollector<'a>,
+}
+
+impl<'a> FirefoxCollector<'a> {
+    pub fn new(config: &'a ExtractionConfig, unified_db: &'a Connection) -> Result<Self> {
+        Ok(Self {
+            base: BaseCollector::new(
+                CollectorType::Firefox.name().to_string(),
+                config,
+                unified_db,
+            ),
+        })
+    }
+
+    fn extract_visits(&mut self, source: &Connection, metrics: &mut Metrics) -> Result<()> {
+        if self.base.config.verbose {
+            println!("  Extracting web visits...");
+        }
+
+        let started = Instant::now();
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+
+        let watermark = self.watermark()?;
+        let mut max_visit_time_seen = watermark.unwrap_or(0);
+
+        let query = format!(
+            r#"
+            SELECT
+                h.id,
+                p.url,
+                p.title,
+                h.visit_date,
+                h.visit_type
+            FROM moz_historyvisits h
+            JOIN moz_places p ON h.place_id = p.id
+            {}
+            ORDER BY h.visit_date
+            "#,
+            if watermark.is_some() {
+                "WHERE h.visit_date > ?"
+            } else {
+                ""
+            }
+        );
+
+        let mut stmt = source.prepare(&query)?;
+
+        let rows = match watermark {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
+        let visits: Vec<(i64, String, Option<String>, i64, i64)> = query_map(rows)?;
+        let rows_scanned = visits.len();
+
+        for (_visit_id, url, title, visit_date, visit_type) in visits {
+            max_visit_time_seen = max_visit_time_seen.max(visit_date);
+
+            // Convert Firefox/PRTime (microseconds since the Unix epoch) timestamp to Unix seconds
+            let timestamp = match timestamp::firefox_to_unix_opt(Some(visit_date)) {
+                Some(ts) => ts,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
+            };
+
+            let transition_type = get_visit_type_name(visit_type);
+            let normalized_transition = classify_visit_type(visit_type).as_str();
+
+            let visit_date_str = visit_date.to_string();
+            let record_hash =
+                make_hash_from_values(&[url.as_str(), visit_date_str.as_str(), "firefox"]);
+
+            self.base.insert_dedup(
+                r#"
+                INSERT OR IGNORE INTO web_visits
+                (record_hash, url, title, visit_time, visit_duration_seconds, transition_type, transition, browser)
+                VALUES (?, ?, ?, ?, NULL, ?, ?, 'firefox')
+                "#,
+                rusqlite::params![
+                    record_hash,
+                    url,
+                    title,
+                    timestamp,
+                    transition_type,
+                    normalized_transition
+                ],
+            )?;
+        }
+
+        if max_visit_time_seen > watermark.unwrap_or(0) {
+            self.advance_watermark(max_visit_time_seen)?;
+        }
+
+        metrics.record_step(
+            self.name(),
+            "visits",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a> Collector for FirefoxCollector<'a> {
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn source_paths(&self) -> Vec<String> {
+        CollectorType::Firefox.default_source_paths()
+    }
+
+    fn extract(&mut self, source_conn: &Connection, metrics: &mut Metrics) -> Result<()> {
+        self.extract_visits(source_conn, metrics)
+    }
+
+    fn config(&self) -> &ExtractionConfig {
+        self.base.config
+    }
+
+    fn unified_db(&self) -> &Connection {
+        self.base.unified_db
+    }
+
+    fn get_counts(&self) -> (usize, usize) {
+        (self.base.records_added, self.base.records_skipped)
+    }
+
+    fn increment_added(&mut self) {
+        self.base.records_added += 1;
+    }
+
+    fn increment_skipped(&mut self) {
+        self.base.records_skipped += 1;
+    }
+}
@@ -1,12 +1,17 @@
 //! KnowledgeC collector for Apple's knowledgeC.db (Screen Time, App Usage, Bluetooth, etc.)
 
 use crate::collectors::base::{BaseCollector, Collector};
+use crate::collectors::bluetooth_metadata;
+use crate::collectors::knowledgec_streams::{self, StreamSpec, ValueColumn};
 use crate::collectors::utils::make_hash_from_values;
 use crate::error::Result;
+use crate::metrics::{Metrics, StepMetrics};
 use crate::timestamp;
 use crate::types::{CollectorType, ExtractionConfig};
+use rusqlite::types::ValueRef;
 use rusqlite::Connection;
 use std::collections::HashMap;
+use std::time::Instant;
 
 pub struct KnowledgeCCollector<'a> {
     base: BaseCollector<'a>,
@@ -41,14 +46,25 @@ impl<'a> KnowledgeCCollector<'a> {
         Ok(map)
     }
 
-    fn extract_app_usage(&mut self, source: &Connection) -> Result<()> {
+    fn extract_app_usage(
+        &mut self,
+        source: &Connection,
+        metrics: &mut Metrics,
+        since: Option<i64>,
+    ) -> Result<i64> {
         if self.base.config.verbose {
             println!("  Extracting app usage...");
         }
 
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+        let mut max_start_seen = since.unwrap_or(0);
+
         let device_map = self.get_device_mapping(source)?;
 
-        let mut stmt = source.prepare(
+        let query = format!(
             r#"
             SELECT
                 o.Z_PK,
@@ -60,27 +76,49 @@ impl<'a> KnowledgeCCollector<'a> {
             LEFT JOIN ZSOURCE s ON o.ZSOURCE = s.Z_PK
             WHERE o.ZSTREAMNAME = '/app/usage'
               AND o.ZVALUESTRING IS NOT NULL
+              {}
             ORDER BY o.ZSTARTDATE
             "#,
-        )?;
+            if since.is_some() {
+                "AND o.ZSTARTDATE > ?"
+            } else {
+                ""
+            }
+        );
 
-        let mut rows = stmt.query([])?;
+        let mut stmt = source.prepare(&query)?;
+        let mut rows = match since {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
             let _pk: i64 = row.get(0)?;
             let bundle_id: Option<String> = row.get(1)?;
             let start_date: Option<f64> = row.get(2)?;
             let end_date: Option<f64> = row.get(3)?;
             let device_id: Option<String> = row.get(4)?;
 
+            if let Some(start) = start_date {
+                max_start_seen = max_start_seen.max(start as i64);
+            }
+
             let bundle_id = match bundle_id {
                 Some(b) if !b.is_empty() => b,
-                _ => continue,
+                _ => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let start_time = match timestamp::apple_to_unix_opt(start_date) {
                 Some(ts) => ts,
-                None => continue,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let end_time = timestamp::apple_to_unix_opt(end_date);
@@ -90,7 +128,9 @@ impl<'a> KnowledgeCCollector<'a> {
                 _ => None,
             };
 
-            let device_model = device_id.as_ref().and_then(|id| device_map.get(id).cloned());
+            let device_model = device_id
+                .as_ref()
+                .and_then(|id| device_map.get(id).cloned());
 
             let start_time_str = start_time.to_string();
             let device_id_str = device_id.as_deref().unwrap_or("");
@@ -100,9 +140,9 @@ impl<'a> KnowledgeCCollector<'a> {
                 device_id_str,
             ]);
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO app_usage
+                INSERT OR IGNORE INTO app_usage
                 (record_hash, bundle_id, start_time, end_time, duration_seconds, device_id, device_model)
                 VALUES (?, ?, ?, ?, ?, ?, ?)
                 "#,
@@ -115,26 +155,41 @@ impl<'a> KnowledgeCCollector<'a> {
                     device_id,
                     device_model,
                 ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
         }
 
-        Ok(())
+        metrics.record_step(
+            self.name(),
+            "app_usage",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
+        Ok(max_start_seen)
     }
 
-    fn extract_bluetooth(&mut self, source: &Connection) -> Result<()> {
+    fn extract_bluetooth(
+        &mut self,
+        source: &Connection,
+        metrics: &mut Metrics,
+        since: Option<i64>,
+    ) -> Result<i64> {
         if self.base.config.verbose {
             println!("  Extracting bluetooth connections...");
         }
 
-        let mut stmt = source.prepare(
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+        let mut max_start_seen = since.unwrap_or(0);
+
+        let query = format!(
             r#"
             SELECT
                 o.Z_PK,
@@ -147,13 +202,25 @@ impl<'a> KnowledgeCCollector<'a> {
             FROM ZOBJECT o
             LEFT JOIN ZSTRUCTUREDMETADATA sm ON o.ZSTRUCTUREDMETADATA = sm.Z_PK
             WHERE o.ZSTREAMNAME = '/bluetooth/isConnected'
+              {}
             ORDER BY o.ZSTARTDATE
             "#,
-        )?;
+            if since.is_some() {
+                "AND o.ZSTARTDATE > ?"
+            } else {
+                ""
+            }
+        );
 
-        let mut rows = stmt.query([])?;
+        let mut stmt = source.prepare(&query)?;
+        let mut rows = match since {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
             let _pk: i64 = row.get(0)?;
             let start_date: Option<f64> = row.get(1)?;
             let end_date: Option<f64> = row.get(2)?;
@@ -162,9 +229,16 @@ impl<'a> KnowledgeCCollector<'a> {
             let device_type: Option<i64> = row.get(5)?;
             let product_id: Option<i64> = row.get(6)?;
 
+            if let Some(start) = start_date {
+                max_start_seen = max_start_seen.max(start as i64);
+            }
+
             let start_time = match timestamp::apple_to_unix_opt(start_date) {
                 Some(ts) => ts,
-                None => continue,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let end_time = timestamp::apple_to_unix_opt(end_date);
@@ -176,16 +250,16 @@ impl<'a> KnowledgeCCollector<'a> {
 
             let address_str = address.as_deref().unwrap_or("");
             let start_time_str = start_time.to_string();
-            let record_hash = make_hash_from_values(&[
-                address_str,
-                start_time_str.as_str(),
-            ]);
+            let record_hash = make_hash_from_values(&[address_str, start_time_str.as_str()]);
 
-            match self.base.unified_db.execute(
+            let vendor = bluetooth_metadata::vendor_from_address(address.as_deref());
+            let model_name = bluetooth_metadata::apple_product_name(product_id);
+
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO bluetooth_connections
-                (record_hash, device_name, device_address, device_type, product_id, start_time, end_time, duration_seconds)
-                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                INSERT OR IGNORE INTO bluetooth_connections
+                (record_hash, device_name, device_address, device_type, product_id, vendor, model_name, start_time, end_time, duration_seconds)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 "#,
                 rusqlite::params![
                     record_hash,
@@ -193,30 +267,47 @@ impl<'a> KnowledgeCCollector<'a> {
                     address,
                     device_type,
                     product_id,
+                    vendor,
+                    model_name,
                     start_time,
                     end_time,
                     duration,
                 ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
         }
 
-        Ok(())
+        metrics.record_step(
+            self.name(),
+            "bluetooth",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
+        Ok(max_start_seen)
     }
 
-    fn extract_notifications(&mut self, source: &Connection) -> Result<()> {
+    fn extract_notifications(
+        &mut self,
+        source: &Connection,
+        metrics: &mut Metrics,
+        since: Option<i64>,
+    ) -> Result<i64> {
         if self.base.config.verbose {
             println!("  Extracting notifications...");
         }
 
-        let mut stmt = source.prepare(
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+        let mut max_start_seen = since.unwrap_or(0);
+
+        let query = format!(
             r#"
             SELECT
                 o.Z_PK,
@@ -226,21 +317,40 @@ impl<'a> KnowledgeCCollector<'a> {
             FROM ZOBJECT o
             LEFT JOIN ZSOURCE s ON o.ZSOURCE = s.Z_PK
             WHERE o.ZSTREAMNAME = '/notification/usage'
+              {}
             ORDER BY o.ZSTARTDATE
             "#,
-        )?;
+            if since.is_some() {
+                "AND o.ZSTARTDATE > ?"
+            } else {
+                ""
+            }
+        );
 
-        let mut rows = stmt.query([])?;
+        let mut stmt = source.prepare(&query)?;
+        let mut rows = match since {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
             let _pk: i64 = row.get(0)?;
             let event_type: Option<String> = row.get(1)?;
             let start_date: Option<f64> = row.get(2)?;
             let bundle_id: Option<String> = row.get(3)?;
 
+            if let Some(start) = start_date {
+                max_start_seen = max_start_seen.max(start as i64);
+            }
+
             let timestamp = match timestamp::apple_to_unix_opt(start_date) {
                 Some(ts) => ts,
-                None => continue,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             // Use bundle_id from source, fall back to event type if it looks like a bundle
@@ -248,7 +358,10 @@ impl<'a> KnowledgeCCollector<'a> {
 
             let app_bundle = match app_bundle {
                 Some(b) if !b.is_empty() && b != "Receive" && b != "Dismiss" => b,
-                _ => continue,
+                _ => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let timestamp_str = timestamp.to_string();
@@ -259,32 +372,47 @@ impl<'a> KnowledgeCCollector<'a> {
                 event_type_str,
             ]);
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO notifications (record_hash, bundle_id, event_type, timestamp)
+                INSERT OR IGNORE INTO notifications (record_hash, bundle_id, event_type, timestamp)
                 VALUES (?, ?, ?, ?)
                 "#,
                 rusqlite::params![record_hash, app_bundle, event_type, timestamp],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
         }
 
-        Ok(())
+        metrics.record_step(
+            self.name(),
+            "notifications",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
+        Ok(max_start_seen)
     }
 
-    fn extract_intents(&mut self, source: &Connection) -> Result<()> {
+    fn extract_intents(
+        &mut self,
+        source: &Connection,
+        metrics: &mut Metrics,
+        since: Option<i64>,
+    ) -> Result<i64> {
         if self.base.config.verbose {
             println!("  Extracting intents...");
         }
 
-        let mut stmt = source.prepare(
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+        let mut max_start_seen = since.unwrap_or(0);
+
+        let query = format!(
             r#"
             SELECT
                 o.Z_PK,
@@ -296,59 +424,90 @@ impl<'a> KnowledgeCCollector<'a> {
             LEFT JOIN ZSTRUCTUREDMETADATA sm ON o.ZSTRUCTUREDMETADATA = sm.Z_PK
             LEFT JOIN ZSOURCE s ON o.ZSOURCE = s.Z_PK
             WHERE o.ZSTREAMNAME = '/app/intents'
+              {}
             ORDER BY o.ZSTARTDATE
             "#,
-        )?;
+            if since.is_some() {
+                "AND o.ZSTARTDATE > ?"
+            } else {
+                ""
+            }
+        );
 
-        let mut rows = stmt.query([])?;
+        let mut stmt = source.prepare(&query)?;
+        let mut rows = match since {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
             let _pk: i64 = row.get(0)?;
             let start_date: Option<f64> = row.get(1)?;
             let intent_class: Option<String> = row.get(2)?;
             let intent_verb: Option<String> = row.get(3)?;
             let bundle_id: Option<String> = row.get(4)?;
 
+            if let Some(start) = start_date {
+                max_start_seen = max_start_seen.max(start as i64);
+            }
+
             let timestamp = match timestamp::apple_to_unix_opt(start_date) {
                 Some(ts) => ts,
-                None => continue,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let intent_class_str = intent_class.as_deref().unwrap_or("");
             let bundle_id_str = bundle_id.as_deref().unwrap_or("");
             let timestamp_str = timestamp.to_string();
-            let record_hash = make_hash_from_values(&[
-                intent_class_str,
-                bundle_id_str,
-                timestamp_str.as_str(),
-            ]);
+            let record_hash =
+                make_hash_from_values(&[intent_class_str, bundle_id_str, timestamp_str.as_str()]);
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO intents (record_hash, intent_class, intent_verb, bundle_id, timestamp)
+                INSERT OR IGNORE INTO intents (record_hash, intent_class, intent_verb, bundle_id, timestamp)
                 VALUES (?, ?, ?, ?, ?)
                 "#,
                 rusqlite::params![record_hash, intent_class, intent_verb, bundle_id, timestamp],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
         }
 
-        Ok(())
+        metrics.record_step(
+            self.name(),
+            "intents",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
+        Ok(max_start_seen)
     }
 
-    fn extract_display_state(&mut self, source: &Connection) -> Result<()> {
+    fn extract_display_state(
+        &mut self,
+        source: &Connection,
+        metrics: &mut Metrics,
+        since: Option<i64>,
+    ) -> Result<i64> {
         if self.base.config.verbose {
             println!("  Extracting display state...");
         }
 
-        let mut stmt = source.prepare(
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+        let mut max_start_seen = since.unwrap_or(0);
+
+        let query = format!(
             r#"
             SELECT
                 o.Z_PK,
@@ -357,21 +516,40 @@ impl<'a> KnowledgeCCollector<'a> {
                 o.ZENDDATE
             FROM ZOBJECT o
             WHERE o.ZSTREAMNAME = '/display/isBacklit'
+              {}
             ORDER BY o.ZSTARTDATE
             "#,
-        )?;
+            if since.is_some() {
+                "AND o.ZSTARTDATE > ?"
+            } else {
+                ""
+            }
+        );
 
-        let mut rows = stmt.query([])?;
+        let mut stmt = source.prepare(&query)?;
+        let mut rows = match since {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
             let _pk: i64 = row.get(0)?;
             let is_backlit: Option<i64> = row.get(1)?;
             let start_date: Option<f64> = row.get(2)?;
             let end_date: Option<f64> = row.get(3)?;
 
+            if let Some(start) = start_date {
+                max_start_seen = max_start_seen.max(start as i64);
+            }
+
             let start_time = match timestamp::apple_to_unix_opt(start_date) {
                 Some(ts) => ts,
-                None => continue,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let end_time = timestamp::apple_to_unix_opt(end_date);
@@ -383,29 +561,232 @@ impl<'a> KnowledgeCCollector<'a> {
 
             let start_time_str = start_time.to_string();
             let is_backlit_str = is_backlit.unwrap_or(0).to_string();
-            let record_hash = make_hash_from_values(&[
-                start_time_str.as_str(),
-                is_backlit_str.as_str(),
-            ]);
+            let record_hash =
+                make_hash_from_values(&[start_time_str.as_str(), is_backlit_str.as_str()]);
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO display_state (record_hash, is_backlit, start_time, end_time, duration_seconds)
+                INSERT OR IGNORE INTO display_state (record_hash, is_backlit, start_time, end_time, duration_seconds)
                 VALUES (?, ?, ?, ?, ?)
                 "#,
                 rusqlite::params![record_hash, is_backlit, start_time, end_time, duration],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
+            )?;
+        }
+
+        metrics.record_step(
+            self.name(),
+            "display_state",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
+        Ok(max_start_seen)
+    }
+
+    /// Walk every [`StreamSpec`] (the built-in defaults plus any the user added via
+    /// `ExtractionConfig::with_extra_knowledgec_streams`) and capture each stream into the
+    /// generic `knowledgec_events` table. See [`knowledgec_streams`] for why these don't get
+    /// their own hand-written `extract_*` method and table the way bluetooth/app_usage do.
+    ///
+    /// Returns the highest `ZSTARTDATE` seen across every stream, for the caller to fold into
+    /// the collector-wide watermark alongside the hand-written extractors.
+    fn extract_generic_streams(
+        &mut self,
+        source: &Connection,
+        metrics: &mut Metrics,
+        since: Option<i64>,
+    ) -> Result<i64> {
+        let mut specs = knowledgec_streams::default_stream_specs();
+        specs.extend(self.base.config.extra_knowledgec_streams.iter().cloned());
+
+        let mut max_start_seen = since.unwrap_or(0);
+        for spec in &specs {
+            let seen = self.extract_stream(source, metrics, spec, since)?;
+            max_start_seen = max_start_seen.max(seen);
+        }
+
+        Ok(max_start_seen)
+    }
+
+    fn extract_stream(
+        &mut self,
+        source: &Connection,
+        metrics: &mut Metrics,
+        spec: &StreamSpec,
+        since: Option<i64>,
+    ) -> Result<i64> {
+        if self.base.config.verbose {
+            println!("  Extracting {}...", spec.label);
+        }
+
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+        let mut max_start_seen = since.unwrap_or(0);
+
+        let bundle_id_column = if spec.include_bundle_id {
+            "s.ZBUNDLEID"
+        } else {
+            "NULL"
+        };
+
+        let metadata_columns: String = spec
+            .structured_metadata_keys
+            .iter()
+            .map(|key| format!(", sm.{}", key))
+            .collect();
+
+        let source_join = if spec.include_bundle_id {
+            "LEFT JOIN ZSOURCE s ON o.ZSOURCE = s.Z_PK"
+        } else {
+            ""
+        };
+        let metadata_join = if spec.structured_metadata_keys.is_empty() {
+            ""
+        } else {
+            "LEFT JOIN ZSTRUCTUREDMETADATA sm ON o.ZSTRUCTUREDMETADATA = sm.Z_PK"
+        };
+
+        let query = format!(
+            r#"
+            SELECT o.{value_column}, o.ZSTARTDATE, o.ZENDDATE, {bundle_id_column}{metadata_columns}
+            FROM ZOBJECT o
+            {source_join}
+            {metadata_join}
+            WHERE o.ZSTREAMNAME = ?
+              {watermark_clause}
+            ORDER BY o.ZSTARTDATE
+            "#,
+            value_column = spec.value_column.source_column(),
+            bundle_id_column = bundle_id_column,
+            metadata_columns = metadata_columns,
+            source_join = source_join,
+            metadata_join = metadata_join,
+            watermark_clause = if since.is_some() {
+                "AND o.ZSTARTDATE > ?"
+            } else {
+                ""
+            },
+        );
+
+        let mut stmt = source.prepare(&query)?;
+        let mut rows = match since {
+            Some(wm) => stmt.query(rusqlite::params![spec.stream_name, wm])?,
+            None => stmt.query(rusqlite::params![spec.stream_name])?,
+        };
+
+        while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
+            let start_date: Option<f64> = row.get(1)?;
+            let end_date: Option<f64> = row.get(2)?;
+
+            if let Some(start) = start_date {
+                max_start_seen = max_start_seen.max(start as i64);
             }
+
+            let start_time = match timestamp::apple_to_unix_opt(start_date) {
+                Some(ts) => ts,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
+            };
+            let end_time = timestamp::apple_to_unix_opt(end_date);
+
+            let duration = match (end_time, Some(start_time)) {
+                (Some(end), Some(start)) if end > start => Some((end - start) as f64),
+                _ => None,
+            };
+
+            let value = row_value_to_json(row, 0);
+            let value_text = match spec.value_column {
+                ValueColumn::String => value.as_str().map(|s| s.to_string()),
+                _ => None,
+            };
+            let value_integer = match spec.value_column {
+                ValueColumn::Integer => value.as_i64(),
+                _ => None,
+            };
+            let value_double = match spec.value_column {
+                ValueColumn::Double => value.as_f64(),
+                _ => None,
+            };
+
+            let bundle_id: Option<String> = row.get(3)?;
+
+            let metadata_json = if spec.structured_metadata_keys.is_empty() {
+                None
+            } else {
+                let mut map = serde_json::Map::new();
+                for (i, key) in spec.structured_metadata_keys.iter().enumerate() {
+                    map.insert(key.clone(), row_value_to_json(row, 4 + i));
+                }
+                Some(serde_json::Value::Object(map).to_string())
+            };
+
+            let value_str = value.to_string();
+            let start_time_str = start_time.to_string();
+            let bundle_id_str = bundle_id.as_deref().unwrap_or("");
+            let record_hash = make_hash_from_values(&[
+                spec.stream_name.as_str(),
+                value_str.as_str(),
+                start_time_str.as_str(),
+                bundle_id_str,
+            ]);
+
+            self.base.insert_dedup(
+                r#"
+                INSERT OR IGNORE INTO knowledgec_events
+                (record_hash, stream_name, value_text, value_integer, value_double, bundle_id, metadata_json, start_time, end_time, duration_seconds)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                rusqlite::params![
+                    record_hash,
+                    spec.stream_name,
+                    value_text,
+                    value_integer,
+                    value_double,
+                    bundle_id,
+                    metadata_json,
+                    start_time,
+                    end_time,
+                    duration,
+                ],
+            )?;
         }
 
-        Ok(())
+        metrics.record_step(
+            self.name(),
+            &spec.label,
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
+        Ok(max_start_seen)
+    }
+}
+
+/// Read a `ZOBJECT` column generically, so a column of unknown/variable SQLite type affinity
+/// (as structured-metadata columns are) can still be folded into `metadata_json`.
+fn row_value_to_json(row: &rusqlite::Row<'_>, idx: usize) -> serde_json::Value {
+    match row.get_ref(idx) {
+        Ok(ValueRef::Null) | Err(_) => serde_json::Value::Null,
+        Ok(ValueRef::Integer(i)) => serde_json::Value::from(i),
+        Ok(ValueRef::Real(f)) => serde_json::Value::from(f),
+        Ok(ValueRef::Text(t)) => serde_json::Value::from(String::from_utf8_lossy(t).into_owned()),
+        Ok(ValueRef::Blob(_)) => serde_json::Value::Null,
     }
 }
 
@@ -418,12 +799,31 @@ impl<'a> Collector for KnowledgeCCollector<'a> {
         CollectorType::KnowledgeC.default_source_paths()
     }
 
-    fn extract(&mut self, source_conn: &Connection) -> Result<()> {
-        self.extract_app_usage(source_conn)?;
-        self.extract_bluetooth(source_conn)?;
-        self.extract_notifications(source_conn)?;
-        self.extract_intents(source_conn)?;
-        self.extract_display_state(source_conn)?;
+    fn extract(&mut self, source_conn: &Connection, metrics: &mut Metrics) -> Result<()> {
+        // All five hand-written extractors plus the generic stream extractor read from the same
+        // ZOBJECT table and share this collector's single watermark row, so the watermark is read
+        // once up front and only advanced once at the end - advancing it between sub-extractors
+        // within the same run could cause a later one to skip rows from this very run.
+        let watermark = self.watermark()?;
+        let mut max_start_seen = watermark.unwrap_or(0);
+
+        max_start_seen =
+            max_start_seen.max(self.extract_app_usage(source_conn, metrics, watermark)?);
+        max_start_seen =
+            max_start_seen.max(self.extract_bluetooth(source_conn, metrics, watermark)?);
+        max_start_seen =
+            max_start_seen.max(self.extract_notifications(source_conn, metrics, watermark)?);
+        max_start_seen =
+            max_start_seen.max(self.extract_intents(source_conn, metrics, watermark)?);
+        max_start_seen =
+            max_start_seen.max(self.extract_display_state(source_conn, metrics, watermark)?);
+        max_start_seen =
+            max_start_seen.max(self.extract_generic_streams(source_conn, metrics, watermark)?);
+
+        if max_start_seen > watermark.unwrap_or(0) {
+            self.advance_watermark(max_start_seen)?;
+        }
+
         Ok(())
     }
 
@@ -0,0 +1,146 @@
+//! Config-driven extraction for arbitrary knowledgeC `ZOBJECT` streams.
+//!
+//! [`super::knowledgec::KnowledgeCCollector`] hand-codes an `extract_*` method per stream for the
+//! handful that need bespoke columns (`bluetooth_connections`, `app_usage`, ...). But knowledgeC
+//! carries dozens of other streams worth capturing (`/app/inFocus`, `/device/batteryPercentage`,
+//! `/audio/outputRoute`, `/web/usage`, `/siri/usage`, `/app/locationActivity`, and more a user
+//! might discover on their own device) that all share the same shape: a stream name, one value
+//! column, a start/end time, and optionally a bundle id or a few structured-metadata columns.
+//! [`StreamSpec`] captures that shape once so [`super::knowledgec::KnowledgeCCollector`]'s driver
+//! loop can walk a list of them instead of growing a new hand-written method per stream, and
+//! users can append their own specs via `ExtractionConfig` without touching Rust. Every spec
+//! writes into the single generic `knowledgec_events` table (rather than a table of its own) so
+//! the schema stays static, matching the rest of this crate's no-migrations design.
+
+/// Which `ZOBJECT` column holds a stream's payload, and therefore which typed column on
+/// `knowledgec_events` it should be written into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueColumn {
+    /// `ZVALUESTRING` → `knowledgec_events.value_text`
+    String,
+    /// `ZVALUEINTEGER` → `knowledgec_events.value_integer`
+    Integer,
+    /// `ZVALUEDOUBLE` → `knowledgec_events.value_double`
+    Double,
+}
+
+impl ValueColumn {
+    /// The `ZOBJECT` column name to select for this value type.
+    pub fn source_column(&self) -> &'static str {
+        match self {
+            ValueColumn::String => "ZVALUESTRING",
+            ValueColumn::Integer => "ZVALUEINTEGER",
+            ValueColumn::Double => "ZVALUEDOUBLE",
+        }
+    }
+}
+
+/// Describes how to pull one `ZSTREAMNAME` out of knowledgeC and into `knowledgec_events`.
+#[derive(Debug, Clone)]
+pub struct StreamSpec {
+    /// The `ZOBJECT.ZSTREAMNAME` to match, e.g. `"/app/inFocus"`.
+    pub stream_name: String,
+
+    /// Short label used in metrics/log output (e.g. `"app_in_focus"`).
+    pub label: String,
+
+    /// Which `ZOBJECT` value column this stream's payload lives in.
+    pub value_column: ValueColumn,
+
+    /// Whether to join `ZSOURCE` and capture `ZBUNDLEID`.
+    pub include_bundle_id: bool,
+
+    /// Extra `ZSTRUCTUREDMETADATA` column names to capture verbatim into `metadata_json`.
+    ///
+    /// These must be trusted, locally-configured column names (they're spliced into the SQL
+    /// text, since SQLite has no way to bind a column name as a parameter) — never build a spec
+    /// from untrusted input.
+    pub structured_metadata_keys: Vec<String>,
+}
+
+impl StreamSpec {
+    pub fn new(
+        stream_name: impl Into<String>,
+        label: impl Into<String>,
+        value_column: ValueColumn,
+    ) -> Self {
+        Self {
+            stream_name: stream_name.into(),
+            label: label.into(),
+            value_column,
+            include_bundle_id: false,
+            structured_metadata_keys: Vec::new(),
+        }
+    }
+
+    pub fn with_bundle_id(mut self) -> Self {
+        self.include_bundle_id = true;
+        self
+    }
+
+    pub fn with_structured_metadata_keys(mut self, keys: Vec<String>) -> Self {
+        self.structured_metadata_keys = keys;
+        self
+    }
+}
+
+/// Streams worth capturing out of the box, beyond the bespoke ones
+/// [`super::knowledgec::KnowledgeCCollector`] already hand-codes.
+pub fn default_stream_specs() -> Vec<StreamSpec> {
+    vec![
+        StreamSpec::new("/app/inFocus", "app_in_focus", ValueColumn::String).with_bundle_id(),
+        StreamSpec::new(
+            "/device/batteryPercentage",
+            "battery_percentage",
+            ValueColumn::Integer,
+        ),
+        StreamSpec::new(
+            "/audio/outputRoute",
+            "audio_output_route",
+            ValueColumn::String,
+        ),
+        StreamSpec::new("/web/usage", "safari_web_usage", ValueColumn::String).with_bundle_id(),
+        StreamSpec::new("/siri/usage", "siri_usage", ValueColumn::String),
+        StreamSpec::new(
+            "/app/locationActivity",
+            "app_location_activity",
+            ValueColumn::String,
+        )
+        .with_bundle_id(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_stream_specs_cover_requested_streams() {
+        let specs = default_stream_specs();
+        let names: Vec<&str> = specs.iter().map(|s| s.stream_name.as_str()).collect();
+
+        assert!(names.contains(&"/app/inFocus"));
+        assert!(names.contains(&"/device/batteryPercentage"));
+        assert!(names.contains(&"/audio/outputRoute"));
+        assert!(names.contains(&"/web/usage"));
+        assert!(names.contains(&"/siri/usage"));
+        assert!(names.contains(&"/app/locationActivity"));
+    }
+
+    #[test]
+    fn test_value_column_source_column_names() {
+        assert_eq!(ValueColumn::String.source_column(), "ZVALUESTRING");
+        assert_eq!(ValueColumn::Integer.source_column(), "ZVALUEINTEGER");
+        assert_eq!(ValueColumn::Double.source_column(), "ZVALUEDOUBLE");
+    }
+
+    #[test]
+    fn test_with_structured_metadata_keys_builder() {
+        let spec = StreamSpec::new("/x/y", "x_y", ValueColumn::String)
+            .with_structured_metadata_keys(vec!["Z_SOME_KEY".to_string()]);
+        assert_eq!(
+            spec.structured_metadata_keys,
+            vec!["Z_SOME_KEY".to_string()]
+        );
+    }
+}
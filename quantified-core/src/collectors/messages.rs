@@ -2,9 +2,11 @@
 
 use crate::collectors::base::{BaseCollector, Collector};
 use crate::error::{Error, Result};
+use crate::metrics::{Metrics, StepMetrics};
 use crate::timestamp;
 use crate::types::{CollectorType, ExtractionConfig};
 use rusqlite::Connection;
+use std::time::Instant;
 
 pub struct MessagesCollector<'a> {
     base: BaseCollector<'a>,
@@ -21,11 +23,16 @@ impl<'a> MessagesCollector<'a> {
         })
     }
 
-    fn extract_contacts(&mut self, source: &Connection) -> Result<()> {
+    fn extract_contacts(&mut self, source: &Connection, metrics: &mut Metrics) -> Result<()> {
         if self.base.config.verbose {
             println!("  Extracting contacts...");
         }
 
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+
         let query = r#"
             SELECT DISTINCT
                 h.id,
@@ -49,6 +56,7 @@ impl<'a> MessagesCollector<'a> {
         let mut rows = stmt.query([])?;
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
             let handle_id: Option<String> = row.get(0)?;
             let service: Option<String> = row.get(1)?;
             let display_name: Option<String> = row.get(2)?;
@@ -56,42 +64,49 @@ impl<'a> MessagesCollector<'a> {
             // Skip if no handle_id
             let handle_id = match handle_id {
                 Some(h) if !h.is_empty() => h,
-                _ => continue,
+                _ => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let record_hash = handle_id.clone(); // handle_id is unique
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO contacts
+                INSERT OR IGNORE INTO contacts
                 (record_hash, handle_id, display_name, service)
                 VALUES (?, ?, ?, ?)
                 "#,
-                rusqlite::params![
-                    record_hash,
-                    handle_id,
-                    display_name,
-                    service,
-                ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+                rusqlite::params![record_hash, handle_id, display_name, service],
+            )?;
         }
 
+        metrics.record_step(
+            self.name(),
+            "contacts",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
         Ok(())
     }
 
-    fn extract_chats(&mut self, source: &Connection) -> Result<()> {
+    fn extract_chats(&mut self, source: &Connection, metrics: &mut Metrics) -> Result<()> {
         if self.base.config.verbose {
             println!("  Extracting chats...");
         }
 
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+
         let query = r#"
             SELECT
                 c.ROWID,
@@ -117,6 +132,7 @@ impl<'a> MessagesCollector<'a> {
         let mut rows = stmt.query([])?;
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
             let guid: Option<String> = row.get(1)?;
             let identifier: Option<String> = row.get(2)?;
             let display_name: Option<String> = row.get(3)?;
@@ -126,15 +142,18 @@ impl<'a> MessagesCollector<'a> {
             // Skip if no guid
             let guid = match guid {
                 Some(g) if !g.is_empty() => g,
-                _ => continue,
+                _ => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let last_message_time = timestamp::apple_nano_to_unix_opt(last_msg);
             let record_hash = guid.clone(); // guid is already unique
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO chats
+                INSERT OR IGNORE INTO chats
                 (record_hash, chat_identifier, display_name, participant_count, last_message_time)
                 VALUES (?, ?, ?, ?, ?)
                 "#,
@@ -145,26 +164,41 @@ impl<'a> MessagesCollector<'a> {
                     participants,
                     last_message_time,
                 ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
         }
 
+        metrics.record_step(
+            self.name(),
+            "chats",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
         Ok(())
     }
 
-    fn extract_messages(&mut self, source: &Connection) -> Result<()> {
+    fn extract_messages(&mut self, source: &Connection, metrics: &mut Metrics) -> Result<()> {
         if self.base.config.verbose {
             println!("  Extracting messages...");
         }
 
-        let query = r#"
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+
+        // Incremental mode: only scan messages newer than our last watermark, relying on the
+        // record-hash dedup as a correctness backstop rather than the sole dedup mechanism.
+        let watermark = self.watermark()?;
+        let mut max_date_seen = watermark.unwrap_or(0);
+
+        let query = format!(
+            r#"
             SELECT
                 m.ROWID,
                 m.guid,
@@ -183,27 +217,42 @@ impl<'a> MessagesCollector<'a> {
             LEFT JOIN handle h ON m.handle_id = h.ROWID
             LEFT JOIN chat_message_join cmj ON m.ROWID = cmj.message_id
             LEFT JOIN chat c ON cmj.chat_id = c.ROWID
+            {}
             ORDER BY m.date
-            "#;
+            "#,
+            if watermark.is_some() {
+                "WHERE m.date > ?"
+            } else {
+                ""
+            }
+        );
 
-        let mut stmt = source.prepare(query).map_err(|e| {
+        let mut stmt = source.prepare(&query).map_err(|e| {
             Error::sql_error(
                 "extract_messages",
-                query,
+                query.clone(),
                 e,
                 "Verify Messages database schema. Check that message, handle, chat, chat_message_join, and attachment tables exist",
             )
         })?;
 
-        let mut rows = stmt.query([])?;
+        let mut rows = match watermark {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
+            let row_id: Option<i64> = row.get(0)?;
             let guid: Option<String> = row.get(1)?;
             let text: Option<String> = row.get(2)?;
             let is_from_me: Option<i64> = row.get(3)?;
-            let date: Option<i64> = row.get(4)?;
-            let date_read: Option<i64> = row.get(5)?;
-            let date_delivered: Option<i64> = row.get(6)?;
+            let date: Option<i64> = crate::row::get_checked(row, 4, "message", "date", row_id)?;
+            let date_read: Option<i64> =
+                crate::row::get_checked(row, 5, "message", "date_read", row_id)?;
+            let date_delivered: Option<i64> =
+                crate::row::get_checked(row, 6, "message", "date_delivered", row_id)?;
             let handle_id: Option<String> = row.get(7)?;
             let chat_guid: Option<String> = row.get(8)?;
             let service: Option<String> = row.get(9)?;
@@ -212,17 +261,27 @@ impl<'a> MessagesCollector<'a> {
             // Skip if no guid
             let guid = match guid {
                 Some(g) if !g.is_empty() => g,
-                _ => continue,
+                _ => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             // Convert timestamps
             let timestamp = match timestamp::apple_nano_to_unix_opt(date) {
                 Some(ts) => ts,
-                None => continue,
+                None => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
             let read_time = timestamp::apple_nano_to_unix_opt(date_read);
             let delivered_time = timestamp::apple_nano_to_unix_opt(date_delivered);
 
+            if let Some(d) = date {
+                max_date_seen = max_date_seen.max(d);
+            }
+
             let has_attachment = match attachment_count {
                 Some(count) if count > 0 => 1,
                 _ => 0,
@@ -230,9 +289,9 @@ impl<'a> MessagesCollector<'a> {
 
             let record_hash = guid.clone(); // guid is already unique
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO messages
+                INSERT OR IGNORE INTO messages
                 (record_hash, text, is_from_me, timestamp, date_read, date_delivered,
                  handle_id, chat_id, service, has_attachment)
                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
@@ -249,17 +308,25 @@ impl<'a> MessagesCollector<'a> {
                     service,
                     has_attachment,
                 ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
+        }
+
+        if max_date_seen > watermark.unwrap_or(0) {
+            self.advance_watermark(max_date_seen)?;
         }
 
+        metrics.record_step(
+            self.name(),
+            "messages",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
         Ok(())
     }
 }
@@ -273,7 +340,7 @@ impl<'a> Collector for MessagesCollector<'a> {
         CollectorType::Messages.default_source_paths()
     }
 
-    fn extract(&mut self, source_conn: &Connection) -> Result<()> {
+    fn extract(&mut self, source_conn: &Connection, metrics: &mut Metrics) -> Result<()> {
         // First, verify we can read from the database (permission check)
         let can_read = source_conn
             .query_row("SELECT COUNT(*) FROM message LIMIT 1", [], |_| Ok(()))
@@ -286,17 +353,14 @@ impl<'a> Collector for MessagesCollector<'a> {
         }
 
         // Extract in order with detailed error context
-        self.extract_contacts(source_conn).map_err(|e| {
-            Error::ExtractionFailed(format!("Failed to extract contacts: {}", e))
-        })?;
+        self.extract_contacts(source_conn, metrics)
+            .map_err(|e| Error::ExtractionFailed(format!("Failed to extract contacts: {}", e)))?;
 
-        self.extract_chats(source_conn).map_err(|e| {
-            Error::ExtractionFailed(format!("Failed to extract chats: {}", e))
-        })?;
+        self.extract_chats(source_conn, metrics)
+            .map_err(|e| Error::ExtractionFailed(format!("Failed to extract chats: {}", e)))?;
 
-        self.extract_messages(source_conn).map_err(|e| {
-            Error::ExtractionFailed(format!("Failed to extract messages: {}", e))
-        })?;
+        self.extract_messages(source_conn, metrics)
+            .map_err(|e| Error::ExtractionFailed(format!("Failed to extract messages: {}", e)))?;
 
         Ok(())
     }
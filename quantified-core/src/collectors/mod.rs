@@ -1,10 +1,22 @@
 //! Data collectors for various macOS databases
 
 pub mod base;
+pub mod bluetooth_metadata;
+pub mod brave;
 pub mod chrome;
+pub mod chrome_live;
+pub mod chromium;
+pub mod edge;
+pub mod firefox;
 pub mod knowledgec;
+pub mod knowledgec_streams;
 pub mod messages;
+#[cfg(feature = "rss")]
+pub mod podcast_downloads;
+#[cfg(feature = "rss")]
+pub mod podcast_feed;
 pub mod podcasts;
+pub mod safari;
 pub mod utils;
 
 pub use base::Collector;
@@ -20,17 +32,24 @@ pub fn create_collector<'a>(
     unified_db: &'a Connection,
 ) -> Result<Box<dyn Collector + 'a>> {
     match collector_type {
-        CollectorType::Messages => {
-            Ok(Box::new(messages::MessagesCollector::new(config, unified_db)?))
-        }
-        CollectorType::Chrome => {
-            Ok(Box::new(chrome::ChromeCollector::new(config, unified_db)?))
-        }
-        CollectorType::KnowledgeC => {
-            Ok(Box::new(knowledgec::KnowledgeCCollector::new(config, unified_db)?))
-        }
-        CollectorType::Podcasts => {
-            Ok(Box::new(podcasts::PodcastsCollector::new(config, unified_db)?))
-        }
+        CollectorType::Messages => Ok(Box::new(messages::MessagesCollector::new(
+            config, unified_db,
+        )?)),
+        CollectorType::Chrome => Ok(Box::new(chrome::ChromeCollector::new(config, unified_db)?)),
+        CollectorType::ChromeLive => Ok(Box::new(chrome_live::ChromeLiveCollector::new(
+            config, unified_db,
+        )?)),
+        CollectorType::KnowledgeC => Ok(Box::new(knowledgec::KnowledgeCCollector::new(
+            config, unified_db,
+        )?)),
+        CollectorType::Podcasts => Ok(Box::new(podcasts::PodcastsCollector::new(
+            config, unified_db,
+        )?)),
+        CollectorType::Firefox => Ok(Box::new(firefox::FirefoxCollector::new(
+            config, unified_db,
+        )?)),
+        CollectorType::Safari => Ok(Box::new(safari::SafariCollector::new(config, unified_db)?)),
+        CollectorType::Brave => Ok(Box::new(brave::BraveCollector::new(config, unified_db)?)),
+        CollectorType::Edge => Ok(Box::new(edge::EdgeCollector::new(config, unified_db)?)),
     }
 }
@@ -0,0 +1,336 @@
+//! Download episode audio files referenced by `podcast_episodes.enclosure_url` (populated by
+//! [`crate::collectors::podcast_feed`]) into `ExtractionConfig.output_dir`, with a small worker
+//! pool, HTTP range-resume for partial downloads, and a `podcast_downloads` table tracking
+//! progress so a re-run skips episodes that already finished.
+//!
+//! Only compiled in behind the `rss` Cargo feature, since it shares that feature's `ureq` HTTP
+//! dependency and only ever has work to do once [`crate::collectors::podcast_feed::sync_feeds`]
+//! has backfilled `enclosure_url`. This is a standalone, caller-driven action — unlike
+//! `sync_feeds`, it isn't run automatically as part of every extraction, since which episodes to
+//! fetch audio for ("played", or picked by the user in a host app) is a policy decision for the
+//! caller, not something this crate should assume.
+
+use crate::error::{Error, Result};
+use crate::types::ExtractionConfig;
+use rusqlite::{Connection, OptionalExtension};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// One episode queued for download.
+#[derive(Debug, Clone)]
+struct DownloadJob {
+    record_hash: String,
+    enclosure_url: String,
+    target_path: PathBuf,
+}
+
+/// Outcome of a [`download_episodes`] call.
+#[derive(Debug, Clone, Default)]
+pub struct DownloadSummary {
+    pub completed: usize,
+    pub failed: usize,
+    /// Episodes in `record_hashes` with no `enclosure_url` yet, or already marked `completed`.
+    pub skipped: usize,
+}
+
+/// Download the audio for every episode in `record_hashes` that has an `enclosure_url` and
+/// isn't already marked `completed` in `podcast_downloads`, using up to
+/// `config.download_concurrency` worker threads. Files are written to
+/// `config.output_dir/podcast_audio/` as `<name>.part` and atomically renamed on completion; a
+/// `.part` left over from an interrupted run is resumed with an HTTP `Range` request rather than
+/// restarted.
+pub fn download_episodes(
+    config: &ExtractionConfig,
+    record_hashes: &[String],
+) -> Result<DownloadSummary> {
+    let db_path = config.output_dir.join("unified.db");
+    let dest_dir = config.output_dir.join("podcast_audio");
+    fs::create_dir_all(&dest_dir)
+        .map_err(|e| Error::io_context("download_episodes", dest_dir.display().to_string(), e))?;
+
+    let conn = Connection::open(&db_path)?;
+    let mut jobs = Vec::new();
+    let mut skipped = 0usize;
+    for record_hash in record_hashes {
+        match plan_job(&conn, record_hash, &dest_dir)? {
+            Some(job) => jobs.push(job),
+            None => skipped += 1,
+        }
+    }
+    drop(conn);
+
+    if jobs.is_empty() {
+        return Ok(DownloadSummary {
+            completed: 0,
+            failed: 0,
+            skipped,
+        });
+    }
+
+    let worker_count = config.download_concurrency.max(1).min(jobs.len());
+    let (job_tx, job_rx) = mpsc::channel::<DownloadJob>();
+    let job_rx = Arc::new(Mutex::new(job_rx));
+    let (result_tx, result_rx) = mpsc::channel::<(DownloadJob, Result<u64>)>();
+
+    let workers: Vec<_> = (0..worker_count)
+        .map(|_| {
+            let job_rx = Arc::clone(&job_rx);
+            let result_tx = result_tx.clone();
+            thread::spawn(move || loop {
+                let job = { job_rx.lock().unwrap().recv() };
+                match job {
+                    Ok(job) => {
+                        let outcome = download_one(&job);
+                        if result_tx.send((job, outcome)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(_) => break,
+                }
+            })
+        })
+        .collect();
+    drop(result_tx);
+
+    let job_count = jobs.len();
+    for job in jobs {
+        job_tx.send(job).expect("worker pool shut down early");
+    }
+    drop(job_tx);
+
+    let conn = Connection::open(&db_path)?;
+    let mut completed = 0usize;
+    let mut failed = 0usize;
+    for _ in 0..job_count {
+        let (job, outcome) = result_rx
+            .recv()
+            .expect("worker pool exited before reporting every job");
+        match outcome {
+            Ok(bytes_total) => {
+                mark_completed(&conn, &job.record_hash, bytes_total)?;
+                completed += 1;
+            }
+            Err(e) => {
+                mark_failed(&conn, &job.record_hash)?;
+                if config.verbose {
+                    eprintln!("  Failed to download {}: {}", job.enclosure_url, e);
+                }
+                failed += 1;
+            }
+        }
+    }
+
+    for worker in workers {
+        let _ = worker.join();
+    }
+
+    Ok(DownloadSummary {
+        completed,
+        failed,
+        skipped,
+    })
+}
+
+/// Look up `record_hash`'s episode, decide whether it needs downloading, and — if so — claim it
+/// in `podcast_downloads` (`status = 'downloading'`) before handing back a job.
+fn plan_job(conn: &Connection, record_hash: &str, dest_dir: &Path) -> Result<Option<DownloadJob>> {
+    let episode: Option<(Option<String>, Option<String>, Option<String>)> = conn
+        .query_row(
+            "SELECT enclosure_url, episode_title, show_title FROM podcast_episodes WHERE record_hash = ?1",
+            rusqlite::params![record_hash],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()?;
+
+    let Some((Some(enclosure_url), episode_title, show_title)) = episode else {
+        return Ok(None);
+    };
+
+    let status: Option<String> = conn
+        .query_row(
+            "SELECT status FROM podcast_downloads WHERE record_hash = ?1",
+            rusqlite::params![record_hash],
+            |row| row.get(0),
+        )
+        .optional()?;
+    if status.as_deref() == Some("completed") {
+        return Ok(None);
+    }
+
+    let filename = target_filename(
+        record_hash,
+        show_title.as_deref(),
+        episode_title.as_deref(),
+        &enclosure_url,
+    );
+    let target_path = dest_dir.join(filename);
+
+    conn.execute(
+        "INSERT INTO podcast_downloads (record_hash, local_path, status)
+         VALUES (?1, ?2, 'downloading')
+         ON CONFLICT(record_hash) DO UPDATE SET local_path = excluded.local_path, status = 'downloading'",
+        rusqlite::params![record_hash, target_path.display().to_string()],
+    )?;
+
+    Ok(Some(DownloadJob {
+        record_hash: record_hash.to_string(),
+        enclosure_url,
+        target_path,
+    }))
+}
+
+/// Fetch `job.enclosure_url` to `job.target_path`, resuming from a `.part` file left over from
+/// an earlier attempt. Returns the total file size on success.
+fn download_one(job: &DownloadJob) -> Result<u64> {
+    let part_path = PathBuf::from(format!("{}.part", job.target_path.display()));
+
+    let existing_len = fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+
+    let request = ureq::get(&job.enclosure_url);
+    let request = if existing_len > 0 {
+        request.set("Range", &format!("bytes={}-", existing_len))
+    } else {
+        request
+    };
+
+    let response = request.call().map_err(|e| {
+        Error::ExtractionFailed(format!("Download failed for {}: {}", job.enclosure_url, e))
+    })?;
+
+    // The server only honors the Range header (and thus only needs an append) if it answers
+    // 206 Partial Content; anything else (including a plain 200) means the whole body starts
+    // from the first byte and a half-downloaded `.part` must be overwritten from scratch.
+    let resuming = existing_len > 0 && response.status() == 206;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(resuming)
+        .truncate(!resuming)
+        .open(&part_path)
+        .map_err(|e| Error::io_context("download_one", part_path.display().to_string(), e))?;
+
+    let mut reader = response.into_reader();
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let n = reader
+            .read(&mut buf)
+            .map_err(|e| Error::io_context("download_one", job.enclosure_url.clone(), e))?;
+        if n == 0 {
+            break;
+        }
+        file.write_all(&buf[..n])
+            .map_err(|e| Error::io_context("download_one", part_path.display().to_string(), e))?;
+    }
+    drop(file);
+
+    let bytes_total = fs::metadata(&part_path)
+        .map(|m| m.len())
+        .map_err(|e| Error::io_context("download_one", part_path.display().to_string(), e))?;
+
+    fs::rename(&part_path, &job.target_path)
+        .map_err(|e| Error::io_context("download_one", job.target_path.display().to_string(), e))?;
+
+    Ok(bytes_total)
+}
+
+fn mark_completed(conn: &Connection, record_hash: &str, bytes_total: u64) -> Result<()> {
+    conn.execute(
+        "UPDATE podcast_downloads
+         SET status = 'completed', bytes_total = ?1, bytes_done = ?1, completed_at = strftime('%s', 'now')
+         WHERE record_hash = ?2",
+        rusqlite::params![bytes_total as i64, record_hash],
+    )?;
+    Ok(())
+}
+
+fn mark_failed(conn: &Connection, record_hash: &str) -> Result<()> {
+    conn.execute(
+        "UPDATE podcast_downloads SET status = 'failed' WHERE record_hash = ?1",
+        rusqlite::params![record_hash],
+    )?;
+    Ok(())
+}
+
+/// Build a filesystem-safe `<show> - <episode>.<ext>` filename: strip path separators and other
+/// characters that are reserved or awkward across macOS/Linux/Windows, truncate each half to a
+/// safe length, and guess the extension from the enclosure URL (falling back to `.mp3`).
+fn target_filename(
+    record_hash: &str,
+    show_title: Option<&str>,
+    episode_title: Option<&str>,
+    enclosure_url: &str,
+) -> String {
+    let base = format!(
+        "{} - {}",
+        sanitize_component(show_title.unwrap_or("unknown_show")),
+        sanitize_component(episode_title.unwrap_or(record_hash)),
+    );
+    let ext = enclosure_url
+        .split('?')
+        .next()
+        .unwrap_or(enclosure_url)
+        .rsplit('.')
+        .next()
+        .filter(|e| e.len() <= 5 && e.chars().all(|c| c.is_ascii_alphanumeric()))
+        .unwrap_or("mp3");
+
+    format!("{}.{}", base, ext)
+}
+
+/// Strip characters reserved or awkward in filenames across macOS/Linux/Windows, collapse
+/// surrounding whitespace, and truncate to a safe length.
+fn sanitize_component(s: &str) -> String {
+    let cleaned: String = s
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c => c,
+        })
+        .collect();
+    cleaned.trim().chars().take(80).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_component_strips_reserved_characters() {
+        assert_eq!(
+            sanitize_component("Ep 5: \"What/If?\" <Special>"),
+            "Ep 5_ _What_If__ _Special_"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_component_truncates() {
+        let long = "a".repeat(200);
+        assert_eq!(sanitize_component(&long).len(), 80);
+    }
+
+    #[test]
+    fn test_target_filename_guesses_extension_from_url() {
+        let name = target_filename(
+            "hash1",
+            Some("My Show"),
+            Some("Episode One"),
+            "https://example.com/audio/ep1.mp3?dl=1",
+        );
+        assert_eq!(name, "My Show - Episode One.mp3");
+    }
+
+    #[test]
+    fn test_target_filename_falls_back_to_mp3() {
+        let name = target_filename(
+            "hash1",
+            None,
+            None,
+            "https://example.com/audio/stream?id=abc",
+        );
+        assert_eq!(name, "unknown_show - hash1.mp3");
+    }
+}
@@ -0,0 +1,345 @@
+//! RSS/Atom feed sync to backfill `podcast_episodes` with the full metadata (description, show
+//! notes, publish date, enclosure URL) that Apple's local `MTLibrary.sqlite` cache often leaves
+//! sparse or stale — see [`PodcastsCollector`](super::podcasts::PodcastsCollector), which reads
+//! only that local cache.
+//!
+//! Fetching feeds over the network and parsing their XML are both optional: this module is only
+//! compiled in behind the `rss` Cargo feature (see its `pub mod` declaration in
+//! [`collectors`](super)), so the core extractor stays offline-capable without them. With the
+//! feature off, `PodcastsCollector` simply skips the feed-sync step.
+
+use crate::collectors::base::BaseCollector;
+use crate::collectors::utils::make_hash_from_values;
+use crate::error::{Error, Result};
+use crate::metrics::{Metrics, StepMetrics};
+use crate::timestamp;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+use rusqlite::OptionalExtension;
+use std::time::Instant;
+
+/// One `<item>` (RSS) or `<entry>` (Atom) parsed out of a show's feed.
+#[derive(Debug, Default)]
+struct FeedItem {
+    guid: Option<String>,
+    enclosure_url: Option<String>,
+    title: Option<String>,
+    description: Option<String>,
+    show_notes: Option<String>,
+    pub_date: Option<String>,
+    duration_seconds: Option<f64>,
+}
+
+/// Fetch every subscribed show's feed (`podcast_shows.feed_url`, populated by
+/// [`PodcastsCollector::extract_shows`](super::podcasts::PodcastsCollector)) and use its `<item>`
+/// entries to backfill/correct `podcast_episodes` rows, matched by GUID or enclosure URL. A show
+/// whose feed can't be fetched or parsed is skipped rather than aborting the whole sync, the same
+/// tolerance [`Collector::run`](crate::collectors::base::Collector::run) gives a single bad source
+/// database.
+pub fn sync_feeds(base: &mut BaseCollector, metrics: &mut Metrics) -> Result<()> {
+    if base.config.verbose {
+        println!("  Syncing podcast RSS feeds...");
+    }
+
+    let started = Instant::now();
+    let mut rows_scanned = 0usize;
+    let mut rows_dropped = 0usize;
+    let (added_before, skipped_before) = (base.records_added, base.records_skipped);
+
+    let feed_urls: Vec<String> = {
+        let mut stmt = base.unified_db.prepare(
+            "SELECT DISTINCT feed_url FROM podcast_shows WHERE feed_url IS NOT NULL AND feed_url != ''",
+        )?;
+        let rows = stmt.query_map([], |row| row.get::<_, String>(0))?;
+        rows.collect::<std::result::Result<Vec<_>, _>>()?
+    };
+
+    for feed_url in feed_urls {
+        let body = match fetch_feed(&feed_url) {
+            Ok(body) => body,
+            Err(e) => {
+                if base.config.verbose {
+                    println!("    \u{2717} {}", e);
+                }
+                rows_dropped += 1;
+                continue;
+            }
+        };
+
+        let items = match parse_feed_items(&body) {
+            Ok(items) => items,
+            Err(e) => {
+                if base.config.verbose {
+                    println!("    \u{2717} Failed to parse feed {}: {}", feed_url, e);
+                }
+                rows_dropped += 1;
+                continue;
+            }
+        };
+
+        for item in &items {
+            rows_scanned += 1;
+            if item.guid.is_none() && item.enclosure_url.is_none() {
+                rows_dropped += 1;
+                continue;
+            }
+            upsert_episode(base, item)?;
+        }
+    }
+
+    metrics.record_step(
+        "podcasts",
+        "feed_sync",
+        StepMetrics {
+            rows_scanned,
+            rows_added: base.records_added - added_before,
+            rows_skipped: base.records_skipped - skipped_before,
+            rows_dropped,
+            duration: started.elapsed(),
+        },
+    );
+
+    Ok(())
+}
+
+/// Fetch a feed's raw XML body over HTTP(S).
+fn fetch_feed(feed_url: &str) -> Result<String> {
+    let response = ureq::get(feed_url).call().map_err(|e| {
+        Error::ExtractionFailed(format!("Failed to fetch feed {}: {}", feed_url, e))
+    })?;
+
+    response.into_string().map_err(|e| {
+        Error::ExtractionFailed(format!("Failed to read feed body from {}: {}", feed_url, e))
+    })
+}
+
+/// Insert a new `podcast_episodes` row for a feed item with no local match, or backfill/correct
+/// an existing one matched by GUID or enclosure URL. Fields Apple's local cache already populated
+/// (`episode_title`, `published_at`, `duration_seconds`) are only filled in where still NULL;
+/// `description`/`show_notes` — which the local cache never has at all — are always refreshed
+/// from the feed, since a show can (and does) edit its show notes after publishing.
+fn upsert_episode(base: &mut BaseCollector, item: &FeedItem) -> Result<()> {
+    let guid = item.guid.as_deref();
+    let enclosure_url = item.enclosure_url.as_deref();
+    let published_at = item
+        .pub_date
+        .as_deref()
+        .and_then(timestamp::rfc2822_to_unix_opt);
+
+    let existing_id: Option<i64> = base
+        .unified_db
+        .query_row(
+            "SELECT id FROM podcast_episodes
+             WHERE (guid IS NOT NULL AND guid = ?1) OR (enclosure_url IS NOT NULL AND enclosure_url = ?2)
+             LIMIT 1",
+            rusqlite::params![guid, enclosure_url],
+            |row| row.get(0),
+        )
+        .optional()?;
+
+    match existing_id {
+        Some(id) => {
+            base.unified_db.execute(
+                r#"
+                UPDATE podcast_episodes
+                SET guid = COALESCE(guid, ?1),
+                    enclosure_url = COALESCE(enclosure_url, ?2),
+                    episode_title = COALESCE(episode_title, ?3),
+                    published_at = COALESCE(published_at, ?4),
+                    duration_seconds = COALESCE(duration_seconds, ?5),
+                    description = COALESCE(?6, description),
+                    show_notes = COALESCE(?7, show_notes)
+                WHERE id = ?8
+                "#,
+                rusqlite::params![
+                    guid,
+                    enclosure_url,
+                    item.title,
+                    published_at,
+                    item.duration_seconds,
+                    item.description,
+                    item.show_notes,
+                    id,
+                ],
+            )?;
+            base.records_added += 1;
+        }
+        None => {
+            // Only ever reached with at least one of guid/enclosure_url set (checked by the
+            // caller), so this key is always available to hash.
+            let hash_key = guid.or(enclosure_url).unwrap();
+            let record_hash = make_hash_from_values(&[hash_key]);
+
+            match base.unified_db.execute(
+                r#"
+                INSERT INTO podcast_episodes
+                (record_hash, episode_title, guid, enclosure_url, description, show_notes,
+                 published_at, duration_seconds)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                rusqlite::params![
+                    record_hash,
+                    item.title,
+                    guid,
+                    enclosure_url,
+                    item.description,
+                    item.show_notes,
+                    published_at,
+                    item.duration_seconds,
+                ],
+            ) {
+                Ok(_) => base.records_added += 1,
+                Err(rusqlite::Error::SqliteFailure(err, _))
+                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
+                {
+                    base.records_skipped += 1;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse every `<item>`/`<entry>` out of a feed body. Only the handful of elements needed to
+/// backfill `podcast_episodes` are read; everything else in the feed (channel metadata, other
+/// `itunes:*` tags, etc.) is ignored.
+fn parse_feed_items(body: &str) -> Result<Vec<FeedItem>> {
+    let mut reader = Reader::from_str(body);
+    reader.trim_text(true);
+
+    let mut items = Vec::new();
+    let mut current: Option<FeedItem> = None;
+    let mut current_tag: Option<String> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                match name.as_str() {
+                    "item" | "entry" => current = Some(FeedItem::default()),
+                    "enclosure" => {
+                        if let Some(item) = current.as_mut() {
+                            for attr in e.attributes().flatten() {
+                                if attr.key.as_ref() == b"url" {
+                                    item.enclosure_url =
+                                        Some(String::from_utf8_lossy(&attr.value).into_owned());
+                                }
+                            }
+                        }
+                    }
+                    _ => current_tag = Some(name),
+                }
+            }
+            // `<enclosure url="..."/>` is usually self-closing, which arrives as `Empty`, not `Start`.
+            Ok(Event::Empty(e)) => {
+                if e.name().as_ref() == b"enclosure" {
+                    if let Some(item) = current.as_mut() {
+                        for attr in e.attributes().flatten() {
+                            if attr.key.as_ref() == b"url" {
+                                item.enclosure_url =
+                                    Some(String::from_utf8_lossy(&attr.value).into_owned());
+                            }
+                        }
+                    }
+                }
+            }
+            Ok(Event::Text(e)) => {
+                if let (Some(item), Some(tag)) = (current.as_mut(), current_tag.as_deref()) {
+                    let text = e.unescape().map(|t| t.into_owned()).unwrap_or_default();
+                    match tag {
+                        "title" => item.title = Some(text),
+                        "guid" => item.guid = Some(text),
+                        "pubDate" | "published" => item.pub_date = Some(text),
+                        "description" | "summary" => item.description = Some(text),
+                        "content:encoded" | "itunes:summary" => item.show_notes = Some(text),
+                        "itunes:duration" => item.duration_seconds = parse_itunes_duration(&text),
+                        _ => {}
+                    }
+                }
+            }
+            Ok(Event::End(e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                if (name == "item" || name == "entry") && current.is_some() {
+                    items.push(current.take().unwrap());
+                }
+                current_tag = None;
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(Error::ExtractionFailed(format!(
+                    "Malformed feed XML at position {}: {}",
+                    reader.buffer_position(),
+                    e
+                )))
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(items)
+}
+
+/// Parse an `itunes:duration` value, which shows up in the wild as either a bare seconds count
+/// (`754`) or colon-separated `HH:MM:SS`/`MM:SS` (`01:05:30`, `05:30`).
+fn parse_itunes_duration(value: &str) -> Option<f64> {
+    if let Ok(secs) = value.parse::<f64>() {
+        return Some(secs);
+    }
+
+    let mut seconds = 0u64;
+    for part in value.split(':') {
+        seconds = seconds * 60 + part.parse::<u64>().ok()?;
+    }
+    Some(seconds as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_itunes_duration_bare_seconds() {
+        assert_eq!(parse_itunes_duration("754"), Some(754.0));
+    }
+
+    #[test]
+    fn test_parse_itunes_duration_hh_mm_ss() {
+        assert_eq!(parse_itunes_duration("01:05:30"), Some(3930.0));
+        assert_eq!(parse_itunes_duration("05:30"), Some(330.0));
+    }
+
+    #[test]
+    fn test_parse_itunes_duration_invalid() {
+        assert_eq!(parse_itunes_duration("not a duration"), None);
+    }
+
+    #[test]
+    fn test_parse_feed_items_rss() {
+        let body = r#"<?xml version="1.0"?>
+        <rss><channel>
+            <item>
+                <title>Episode One</title>
+                <guid>abc-123</guid>
+                <pubDate>Sun, 01 Jan 2023 00:00:00 GMT</pubDate>
+                <description>Show description</description>
+                <itunes:duration>754</itunes:duration>
+                <enclosure url="https://example.com/ep1.mp3" type="audio/mpeg"/>
+            </item>
+        </channel></rss>
+        "#;
+
+        let items = parse_feed_items(body).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].title.as_deref(), Some("Episode One"));
+        assert_eq!(items[0].guid.as_deref(), Some("abc-123"));
+        assert_eq!(
+            items[0].enclosure_url.as_deref(),
+            Some("https://example.com/ep1.mp3")
+        );
+        assert_eq!(items[0].duration_seconds, Some(754.0));
+    }
+}
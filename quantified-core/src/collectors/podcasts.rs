@@ -2,9 +2,11 @@
 
 use crate::collectors::base::{BaseCollector, Collector};
 use crate::error::Result;
+use crate::metrics::{Metrics, StepMetrics};
 use crate::timestamp;
 use crate::types::{CollectorType, ExtractionConfig};
 use rusqlite::Connection;
+use std::time::Instant;
 
 pub struct PodcastsCollector<'a> {
     base: BaseCollector<'a>,
@@ -21,11 +23,16 @@ impl<'a> PodcastsCollector<'a> {
         })
     }
 
-    fn extract_shows(&mut self, source: &Connection) -> Result<()> {
+    fn extract_shows(&mut self, source: &Connection, metrics: &mut Metrics) -> Result<()> {
         if self.base.config.verbose {
             println!("  Extracting podcast shows...");
         }
 
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+
         let mut stmt = source.prepare(
             r#"
             SELECT
@@ -44,6 +51,8 @@ impl<'a> PodcastsCollector<'a> {
         let mut rows = stmt.query([])?;
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
             let _pk: i64 = row.get(0)?;
             let uuid: Option<String> = row.get(1)?;
             let title: Option<String> = row.get(2)?;
@@ -55,15 +64,18 @@ impl<'a> PodcastsCollector<'a> {
             // Skip if no uuid
             let uuid = match uuid {
                 Some(u) if !u.is_empty() => u,
-                _ => continue,
+                _ => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
             let subscribed_at = timestamp::apple_to_unix_opt(added_date);
             let record_hash = uuid.clone(); // uuid is already unique
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO podcast_shows
+                INSERT OR IGNORE INTO podcast_shows
                 (record_hash, title, author, feed_url, subscribed_at, episode_count)
                 VALUES (?, ?, ?, ?, ?, ?)
                 "#,
@@ -75,26 +87,41 @@ impl<'a> PodcastsCollector<'a> {
                     subscribed_at,
                     episode_count,
                 ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
         }
 
+        metrics.record_step(
+            self.name(),
+            "shows",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
         Ok(())
     }
 
-    fn extract_episodes(&mut self, source: &Connection) -> Result<()> {
+    fn extract_episodes(&mut self, source: &Connection, metrics: &mut Metrics) -> Result<()> {
         if self.base.config.verbose {
             println!("  Extracting podcast episodes...");
         }
 
-        let mut stmt = source.prepare(
+        let started = Instant::now();
+        let mut rows_scanned = 0usize;
+        let mut rows_dropped = 0usize;
+        let (added_before, skipped_before) = (self.base.records_added, self.base.records_skipped);
+
+        // Incremental mode: only scan episodes with a later play timestamp than our last
+        // watermark, relying on the record-hash dedup as a correctness backstop rather than the
+        // sole dedup mechanism.
+        let watermark = self.watermark()?;
+        let mut max_played_seen = watermark.unwrap_or(0);
+
+        let query = format!(
             r#"
             SELECT
                 e.Z_PK,
@@ -109,14 +136,27 @@ impl<'a> PodcastsCollector<'a> {
                 e.ZPUBDATE
             FROM ZMTEPISODE e
             LEFT JOIN ZMTPODCAST p ON e.ZPODCAST = p.Z_PK
-            WHERE e.ZPLAYCOUNT > 0 OR e.ZPLAYHEAD > 0 OR e.ZLASTDATEPLAYED IS NOT NULL
-            ORDER BY e.ZLASTDATEPLAYED DESC
+            WHERE (e.ZPLAYCOUNT > 0 OR e.ZPLAYHEAD > 0 OR e.ZLASTDATEPLAYED IS NOT NULL)
+            {}
+            ORDER BY e.ZLASTDATEPLAYED
             "#,
-        )?;
+            if watermark.is_some() {
+                "AND e.ZLASTDATEPLAYED > ?"
+            } else {
+                ""
+            }
+        );
 
-        let mut rows = stmt.query([])?;
+        let mut stmt = source.prepare(&query)?;
+
+        let mut rows = match watermark {
+            Some(wm) => stmt.query(rusqlite::params![wm])?,
+            None => stmt.query([])?,
+        };
 
         while let Some(row) = rows.next()? {
+            rows_scanned += 1;
+
             let _pk: i64 = row.get(0)?;
             let uuid: Option<String> = row.get(1)?;
             let title: Option<String> = row.get(2)?;
@@ -131,16 +171,23 @@ impl<'a> PodcastsCollector<'a> {
             // Skip if no uuid
             let uuid = match uuid {
                 Some(u) if !u.is_empty() => u,
-                _ => continue,
+                _ => {
+                    rows_dropped += 1;
+                    continue;
+                }
             };
 
+            if let Some(played) = last_played {
+                max_played_seen = max_played_seen.max(played as i64);
+            }
+
             let last_played_at = timestamp::apple_to_unix_opt(last_played);
             let published_at = timestamp::apple_to_unix_opt(pub_date);
             let record_hash = uuid.clone(); // uuid is already unique
 
-            match self.base.unified_db.execute(
+            self.base.insert_dedup(
                 r#"
-                INSERT INTO podcast_episodes
+                INSERT OR IGNORE INTO podcast_episodes
                 (record_hash, episode_title, show_title, show_uuid, duration_seconds,
                  played_seconds, play_count, last_played_at, published_at)
                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
@@ -156,17 +203,25 @@ impl<'a> PodcastsCollector<'a> {
                     last_played_at,
                     published_at,
                 ],
-            ) {
-                Ok(_) => self.base.records_added += 1,
-                Err(rusqlite::Error::SqliteFailure(err, _))
-                    if err.code == rusqlite::ErrorCode::ConstraintViolation =>
-                {
-                    self.base.records_skipped += 1;
-                }
-                Err(e) => return Err(e.into()),
-            }
+            )?;
         }
 
+        if max_played_seen > watermark.unwrap_or(0) {
+            self.advance_watermark(max_played_seen)?;
+        }
+
+        metrics.record_step(
+            self.name(),
+            "episodes",
+            StepMetrics {
+                rows_scanned,
+                rows_added: self.base.records_added - added_before,
+                rows_skipped: self.base.records_skipped - skipped_before,
+                rows_dropped,
+                duration: started.elapsed(),
+            },
+        );
+
         Ok(())
     }
 }
@@ -180,9 +235,20 @@ impl<'a> Collector for PodcastsCollector<'a> {
         CollectorType::Podcasts.default_source_paths()
     }
 
-    fn extract(&mut self, source_conn: &Connection) -> Result<()> {
-        self.extract_shows(source_conn)?;
-        self.extract_episodes(source_conn)?;
+    fn extract(&mut self, source_conn: &Connection, metrics: &mut Metrics) -> Result<()> {
+        self.extract_shows(source_conn, metrics)?;
+        self.extract_episodes(source_conn, metrics)?;
+
+        // Backfill/correct episode metadata (description, show notes, publish date) from each
+        // subscribed show's own RSS feed, which Apple's local cache above often leaves sparse.
+        // Only compiled in behind the `rss` feature so the core extractor stays offline-capable,
+        // and additionally gated on `feeds_enabled` so a feed-capable build can still opt out
+        // at runtime for a purely local, offline run.
+        #[cfg(feature = "rss")]
+        if self.base.config.feeds_enabled {
+            crate::collectors::podcast_feed::sync_feeds(&mut self.base, metrics)?;
+        }
+
         Ok(())
     }
 
@@ -1,7 +1,60 @@
 //! Utility functions for collectors
 
+use rusqlite::types::FromSql;
+use rusqlite::{Row, Rows};
 use sha2::{Digest, Sha256};
 
+/// Maps a `rusqlite::Row` into a typed Rust value.
+///
+/// Blanket-implemented for tuples of up to ten [`FromSql`] elements so collectors can pull a
+/// whole `SELECT` row out with one type annotation instead of a `row.get(0)?..row.get(n)?`
+/// block. See [`row_extract`] and [`query_map`] for the call sites this enables.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row<'_>) -> rusqlite::Result<Self>;
+}
+
+/// Extract a [`FromRow`] value from a single row.
+pub fn row_extract<T: FromRow>(row: &Row<'_>) -> rusqlite::Result<T> {
+    T::from_row(row)
+}
+
+/// Drain a `rusqlite::Rows` cursor into a `Vec<T>`, mapping each row with [`FromRow`].
+///
+/// This reads the whole result set into memory, unlike a `while let Some(row) = rows.next()? { ... }`
+/// loop, which holds one row at a time; swapping such a loop for
+/// `for item in query_map::<T>(rows)? { ... }` trades that streaming for one typed `Vec`.
+pub fn query_map<T: FromRow>(mut rows: Rows<'_>) -> rusqlite::Result<Vec<T>> {
+    let mut out = Vec::new();
+    while let Some(row) = rows.next()? {
+        out.push(T::from_row(row)?);
+    }
+    Ok(out)
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($($idx:tt => $t:ident),+) => {
+        impl<$($t),+> FromRow for ($($t,)+)
+        where
+            $($t: FromSql),+
+        {
+            fn from_row(row: &Row<'_>) -> rusqlite::Result<Self> {
+                Ok(($(row.get::<usize, $t>($idx)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(0 => A);
+impl_from_row_for_tuple!(0 => A, 1 => B);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H, 8 => I, 9 => J);
+
 /// Create a consistent hash from multiple values
 /// Handles None values by converting them to empty strings
 pub fn make_hash(parts: &[Option<String>]) -> String {
@@ -59,11 +112,7 @@ mod tests {
 
     #[test]
     fn test_make_hash_with_none() {
-        let parts = vec![
-            Some("value1".to_string()),
-            None,
-            Some("value3".to_string()),
-        ];
+        let parts = vec![Some("value1".to_string()), None, Some("value3".to_string())];
         let hash = make_hash(&parts);
         assert_eq!(hash.len(), 32);
     }
@@ -88,4 +137,20 @@ mod tests {
         let hash = make_hash_from_values(&[s1.as_str(), s2]);
         assert_eq!(hash.len(), 32);
     }
+
+    #[test]
+    fn test_query_map_tuple_rows() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE t (a TEXT, b INTEGER);
+             INSERT INTO t VALUES ('x', 1), ('y', 2);",
+        )
+        .unwrap();
+
+        let mut stmt = conn.prepare("SELECT a, b FROM t ORDER BY b").unwrap();
+        let rows = stmt.query([]).unwrap();
+        let results: Vec<(String, i64)> = query_map(rows).unwrap();
+
+        assert_eq!(results, vec![("x".to_string(), 1), ("y".to_string(), 2)]);
+    }
 }
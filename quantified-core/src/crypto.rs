@@ -0,0 +1,134 @@
+//! At-rest encryption helpers for the unified database
+//!
+//! Two modes are supported, selected by how `rusqlite` was built:
+//! - When compiled against a SQLCipher-enabled `rusqlite` (feature `sqlcipher`), the unified
+//!   database is transparently encrypted by issuing `PRAGMA key` before the schema is touched.
+//! - Otherwise, a fallback "envelope" mode wraps the finished `unified.db` file in an
+//!   AES-256-GCM container on close, and unwraps it to a temp file the next time it is opened.
+
+use crate::error::{Error, Result};
+use rusqlite::Connection;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Size of the random IV prepended to an envelope-encrypted file.
+const ENVELOPE_IV_LEN: usize = 12;
+
+/// Derive a 256-bit key from a user-supplied passphrase.
+///
+/// This is a simple one-shot SHA-256 digest rather than a password-hashing KDF (no salt,
+/// no iteration count) because the output only ever protects a local file, not a network
+/// credential; callers who need stronger guarantees should supply a pre-derived key instead
+/// of a short passphrase.
+pub fn derive_key(passphrase: &str) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(passphrase.as_bytes());
+    hasher.finalize().into()
+}
+
+/// Issue the SQLCipher pragmas that must run before any other statement on a fresh connection.
+///
+/// Only a build with the `sqlcipher` feature sends the pragmas; without it this leaves the
+/// connection untouched and returns an error, so callers guard calls to this with that feature.
+#[cfg(feature = "sqlcipher")]
+pub fn apply_sqlcipher_pragmas(conn: &Connection, passphrase: &str) -> Result<()> {
+    conn.pragma_update(None, "key", passphrase)?;
+    conn.pragma_update(None, "cipher_page_size", 4096)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+pub fn apply_sqlcipher_pragmas(_conn: &Connection, _passphrase: &str) -> Result<()> {
+    Err(Error::ExtractionFailed(
+        "encryption_key was set but this build of quantified-core was not compiled with the \
+         `sqlcipher` feature; falling back to envelope encryption instead"
+            .to_string(),
+    ))
+}
+
+/// Wrap a finished database file in an AES-256-GCM envelope: a random 12-byte IV prepended
+/// to the ciphertext, with the authentication tag appended by the AEAD implementation.
+///
+/// Intended to run after the unified connection is closed, so the plaintext file on disk is
+/// only ever the brief window of an active extraction run.
+pub fn encrypt_file_in_place(path: &Path, key: &[u8; 32]) -> Result<()> {
+    use aes_gcm::aead::{Aead, KeyInit, OsRng};
+    use aes_gcm::{AeadCore, Aes256Gcm, Key};
+
+    let plaintext = fs::read(path)
+        .map_err(|e| Error::io_context("encrypt_file_in_place", path.display().to_string(), e))?;
+
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext.as_ref())
+        .map_err(|e| Error::ExtractionFailed(format!("envelope encryption failed: {}", e)))?;
+
+    let mut out = Vec::with_capacity(ENVELOPE_IV_LEN + ciphertext.len());
+    out.extend_from_slice(nonce.as_slice());
+    out.extend_from_slice(&ciphertext);
+
+    fs::write(path, out)
+        .map_err(|e| Error::io_context("encrypt_file_in_place", path.display().to_string(), e))
+}
+
+/// Decrypt an envelope-wrapped file into a fresh temp file, returning its path.
+///
+/// The caller is expected to operate on the returned plaintext copy and re-encrypt the
+/// original (or the temp file, renamed back) on close.
+pub fn decrypt_file_to_temp(path: &Path, key: &[u8; 32]) -> Result<PathBuf> {
+    use aes_gcm::aead::{Aead, KeyInit};
+    use aes_gcm::{Aes256Gcm, Key, Nonce};
+
+    let sealed = fs::read(path)
+        .map_err(|e| Error::io_context("decrypt_file_to_temp", path.display().to_string(), e))?;
+
+    if sealed.len() < ENVELOPE_IV_LEN {
+        return Err(Error::ExtractionFailed(format!(
+            "envelope file too short to contain an IV: {}",
+            path.display()
+        )));
+    }
+
+    let (iv, ciphertext) = sealed.split_at(ENVELOPE_IV_LEN);
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(iv), ciphertext)
+        .map_err(|e| Error::ExtractionFailed(format!("envelope decryption failed: {}", e)))?;
+
+    let temp_path = path.with_extension("db.decrypted-tmp");
+    fs::write(&temp_path, plaintext).map_err(|e| {
+        Error::io_context("decrypt_file_to_temp", temp_path.display().to_string(), e)
+    })?;
+
+    Ok(temp_path)
+}
+
+/// Best-effort check for whether a file looks like an AES-GCM envelope rather than a raw
+/// SQLite file (which always starts with the `SQLite format 3\0` magic header).
+pub fn looks_like_envelope(path: &Path) -> bool {
+    const SQLITE_MAGIC: &[u8] = b"SQLite format 3\0";
+    match fs::read(path) {
+        Ok(bytes) => !bytes.starts_with(SQLITE_MAGIC),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_key_is_deterministic_and_32_bytes() {
+        let a = derive_key("correct horse battery staple");
+        let b = derive_key("correct horse battery staple");
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 32);
+    }
+
+    #[test]
+    fn test_derive_key_differs_per_passphrase() {
+        assert_ne!(derive_key("one"), derive_key("two"));
+    }
+}
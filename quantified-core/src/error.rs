@@ -10,7 +10,9 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Error, Debug)]
 pub enum Error {
     /// Database operation failed with detailed context
-    #[error("Database error in {operation}\n  Source: {source_path}\n  Error: {error}\n  {suggestion}")]
+    #[error(
+        "Database error in {operation}\n  Source: {source_path}\n  Error: {error}\n  {suggestion}"
+    )]
     DatabaseWithContext {
         operation: String,
         source_path: String,
@@ -59,10 +61,36 @@ pub enum Error {
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
-    /// Invalid timestamp conversion
+    /// Invalid timestamp conversion. Kept as a thin wrapper for backward compatibility; new
+    /// call sites that know their table/column should prefer [`Error::ColumnTypeMismatch`] or
+    /// [`Error::ValueOutOfRange`], which point at the offending cell instead of just a message.
     #[error("Invalid timestamp: {0}")]
     InvalidTimestamp(String),
 
+    /// A column's stored SQLite type couldn't be coerced into the Rust type a collector
+    /// expected it to be, e.g. `TEXT` where an `INTEGER` timestamp was expected. See
+    /// [`crate::row::get_checked`].
+    #[error(
+        "{table}.{column} has the wrong type: expected {expected}, got {actual} (row {row_id:?})"
+    )]
+    ColumnTypeMismatch {
+        table: String,
+        column: String,
+        expected: String,
+        actual: String,
+        row_id: Option<i64>,
+    },
+
+    /// An integer column's value doesn't fit in the narrower type a collector needed to coerce
+    /// it into, e.g. a 64-bit count that overflows `u32`. See [`crate::row::checked_i64_to_u32`].
+    #[error("{table}.{column} value {value} does not fit in {target_type}")]
+    ValueOutOfRange {
+        table: String,
+        column: String,
+        value: i64,
+        target_type: &'static str,
+    },
+
     /// Generic extraction error
     #[error("Extraction failed: {0}")]
     ExtractionFailed(String),
@@ -70,6 +98,66 @@ pub enum Error {
     /// Unsupported collector type
     #[error("Unsupported collector type: {0}")]
     UnsupportedCollector(String),
+
+    /// Source database failed its pre-extraction integrity check
+    #[error("Source integrity check failed for {path}\n  Result: {detail}\n  Suggestion: This source may be corrupt or tampered with; re-copy it from the original device before retrying")]
+    IntegrityCheckFailed { path: PathBuf, detail: String },
+
+    /// [`crate::integrity::verify_integrity`] found `database` corrupt: `PRAGMA integrity_check`
+    /// reported problems rather than a single `ok` row.
+    #[error("{database:?} is corrupt: {detail}\n  check_output: {check_output:?}")]
+    Corruption {
+        database: PathBuf,
+        detail: String,
+        check_output: Option<String>,
+    },
+
+    /// [`crate::integrity::verify_checksum`] found a file's content hash didn't match the
+    /// digest taken earlier (e.g. at copy time), indicating mid-copy corruption.
+    #[error("Checksum mismatch for {path:?}\n  expected: {expected}\n  actual:   {actual}")]
+    ChecksumMismatch {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+
+    /// Extraction was cancelled partway through via a
+    /// [`crate::progress::CancellationToken`]
+    #[error("Extraction cancelled")]
+    Cancelled,
+
+    /// [`crate::retry::retry`] gave up after exhausting every attempt on a transient error (see
+    /// [`Error::is_transient`]) without success.
+    #[error("{operation} failed after {attempts} attempt(s); last error: {last_error}")]
+    RetriesExhausted {
+        operation: String,
+        attempts: u32,
+        last_error: String,
+    },
+
+    /// A [`crate::search::search`] query couldn't be parsed — unbalanced quotes, an unknown
+    /// collector prefix, or similar — as distinct from an underlying SQL/FTS5 failure.
+    #[error("Could not parse search query {query:?}: {reason}")]
+    QueryParse { query: String, reason: String },
+
+    /// [`crate::search::search`] was scoped to `collector` (e.g. `messages:dinner plans`), but
+    /// [`crate::search::ensure_search_indexes`] hasn't built that collector's FTS5 index yet —
+    /// distinct from [`Error::QueryParse`] since `collector` is a real, just not-yet-indexed,
+    /// searchable table.
+    #[error("No search index has been built for '{collector}' yet; run an extraction or call ensure_search_indexes first")]
+    SearchIndexMissing { collector: String },
+}
+
+/// Whether an [`Error`] is worth retrying (a transient condition that may clear on its own) or
+/// not (a fatal condition retrying can't fix). Mirrors the fail-stop split sled draws between
+/// recoverable and unrecoverable errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorSeverity {
+    /// Worth retrying as-is — e.g. `SQLITE_BUSY`/`SQLITE_LOCKED` from a source database another
+    /// process is writing to at the same moment.
+    Transient,
+    /// Retrying would not help — e.g. permission denied, source not found.
+    Fatal,
 }
 
 impl Error {
@@ -125,4 +213,184 @@ impl Error {
             .join("\n");
         Error::SourceNotFound { paths: paths_str }
     }
+
+    /// Whether this error is a transient condition worth retrying (see [`crate::retry::retry`])
+    /// rather than surfacing immediately. Only a raw SQLite busy/locked failure qualifies:
+    /// [`Error::DatabaseWithContext`] has already lost the original typed `rusqlite::Error` by
+    /// the time it's constructed, so it (like every other variant) is treated as fatal.
+    pub fn severity(&self) -> ErrorSeverity {
+        match self {
+            Error::Database(rusqlite::Error::SqliteFailure(ffi_err, _))
+                if matches!(
+                    ffi_err.code,
+                    rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked
+                ) =>
+            {
+                ErrorSeverity::Transient
+            }
+            _ => ErrorSeverity::Fatal,
+        }
+    }
+
+    /// Shorthand for `self.severity() == ErrorSeverity::Transient`.
+    pub fn is_transient(&self) -> bool {
+        self.severity() == ErrorSeverity::Transient
+    }
+
+    /// A stable, machine-readable identifier for this error's variant (one code per variant; the
+    /// failure class inside an [`Error::Database`] is not distinguished), so a host app can react
+    /// to e.g. `"DB_PERMISSION_DENIED"` — deep-linking the
+    /// user to Full Disk Access settings — without regex-matching the human-readable
+    /// [`std::fmt::Display`] message. Never changes between crate versions for a given variant;
+    /// new variants get a new code rather than reusing one.
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Error::DatabaseWithContext { .. } => "DB_ERROR",
+            Error::Database(_) => "DB_ERROR",
+            Error::SqlError { .. } => "SQL_FAILED",
+            Error::SourceNotFound { .. } => "SOURCE_NOT_FOUND",
+            Error::CopyFailed(_) => "COPY_FAILED",
+            Error::PermissionDenied { .. } => "DB_PERMISSION_DENIED",
+            Error::DatabaseNotFound(_) => "DB_NOT_FOUND",
+            Error::IoWithContext { .. } => "IO_ERROR",
+            Error::Io(_) => "IO_ERROR",
+            Error::InvalidTimestamp(_) => "INVALID_TIMESTAMP",
+            Error::ColumnTypeMismatch { .. } => "COLUMN_TYPE_MISMATCH",
+            Error::ValueOutOfRange { .. } => "VALUE_OUT_OF_RANGE",
+            Error::ExtractionFailed(_) => "EXTRACTION_FAILED",
+            Error::UnsupportedCollector(_) => "UNSUPPORTED_COLLECTOR",
+            Error::IntegrityCheckFailed { .. } => "INTEGRITY_CHECK_FAILED",
+            Error::Corruption { .. } => "DB_CORRUPTION",
+            Error::ChecksumMismatch { .. } => "CHECKSUM_MISMATCH",
+            Error::Cancelled => "CANCELLED",
+            Error::RetriesExhausted { .. } => "RETRIES_EXHAUSTED",
+            Error::QueryParse { .. } => "QUERY_PARSE_FAILED",
+            Error::SearchIndexMissing { .. } => "SEARCH_INDEX_MISSING",
+        }
+    }
+
+    /// The `(operation, path, suggestion)` a host app's structured logging pipeline might want
+    /// to pull out of this error, for variants that carry them. `path` doubles up as
+    /// "table.column" for the column-level variants, since that's the equivalent "where this
+    /// went wrong" locator when there's no filesystem path involved.
+    fn fields(&self) -> (Option<String>, Option<String>, Option<String>) {
+        match self {
+            Error::DatabaseWithContext {
+                operation,
+                source_path,
+                suggestion,
+                ..
+            } => (
+                Some(operation.clone()),
+                Some(source_path.clone()),
+                Some(suggestion.clone()),
+            ),
+            Error::SqlError {
+                operation,
+                suggestion,
+                ..
+            } => (Some(operation.clone()), None, Some(suggestion.clone())),
+            Error::SourceNotFound { .. } => (
+                None,
+                None,
+                Some("Ensure the application has run and created data".to_string()),
+            ),
+            Error::PermissionDenied { path } => (
+                None,
+                Some(path.display().to_string()),
+                Some(
+                    "Grant Full Disk Access to your application in System Settings > Privacy & \
+                     Security > Full Disk Access"
+                        .to_string(),
+                ),
+            ),
+            Error::DatabaseNotFound(path) => (None, Some(path.display().to_string()), None),
+            Error::IoWithContext {
+                operation, path, ..
+            } => (Some(operation.clone()), Some(path.clone()), None),
+            Error::IntegrityCheckFailed { path, .. } => (
+                None,
+                Some(path.display().to_string()),
+                Some(
+                    "This source may be corrupt or tampered with; re-copy it from the original \
+                     device before retrying"
+                        .to_string(),
+                ),
+            ),
+            Error::Corruption { database, .. } => {
+                (None, Some(database.display().to_string()), None)
+            }
+            Error::ChecksumMismatch { path, .. } => (None, Some(path.display().to_string()), None),
+            Error::RetriesExhausted { operation, .. } => (Some(operation.clone()), None, None),
+            Error::SearchIndexMissing { .. } => (
+                None,
+                None,
+                Some("run an extraction or call ensure_search_indexes first".to_string()),
+            ),
+            Error::ColumnTypeMismatch { table, column, .. }
+            | Error::ValueOutOfRange { table, column, .. } => {
+                (None, Some(format!("{}.{}", table, column)), None)
+            }
+            _ => (None, None, None),
+        }
+    }
+}
+
+/// Serializes as `{ code, message, operation, path, suggestion }` for structured logging
+/// pipelines and host apps that want to aggregate/react to failures by [`Error::error_code`]
+/// rather than parsing the human-readable message. `operation`/`path`/`suggestion` are `null`
+/// for variants that don't carry them.
+impl serde::Serialize for Error {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let (operation, path, suggestion) = self.fields();
+        let mut state = serializer.serialize_struct("Error", 5)?;
+        state.serialize_field("code", self.error_code())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.serialize_field("operation", &operation)?;
+        state.serialize_field("path", &path)?;
+        state.serialize_field("suggestion", &suggestion)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod error_code_tests {
+    use super::*;
+
+    #[test]
+    fn test_error_code_permission_denied() {
+        let err = Error::PermissionDenied {
+            path: PathBuf::from("/tmp/chat.db"),
+        };
+        assert_eq!(err.error_code(), "DB_PERMISSION_DENIED");
+    }
+
+    #[test]
+    fn test_serialize_includes_code_and_suggestion() {
+        let err = Error::PermissionDenied {
+            path: PathBuf::from("/tmp/chat.db"),
+        };
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["code"], "DB_PERMISSION_DENIED");
+        assert_eq!(json["path"], "/tmp/chat.db");
+        assert!(json["suggestion"]
+            .as_str()
+            .unwrap()
+            .contains("Full Disk Access"));
+    }
+
+    #[test]
+    fn test_serialize_null_fields_for_variant_without_them() {
+        let err = Error::Cancelled;
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["code"], "CANCELLED");
+        assert!(json["operation"].is_null());
+        assert!(json["path"].is_null());
+        assert!(json["suggestion"].is_null());
+    }
 }
@@ -0,0 +1,365 @@
+//! Export extraction reports and unified-database tables to portable file formats.
+//!
+//! JSON is always available; YAML and CSV are optional (behind the `yaml`/`csv` Cargo features)
+//! since most callers only need one serializer and pulling in both by default would bloat every
+//! build. See [`crate::uniffi_api::export_report`]/[`crate::uniffi_api::export_database`] for the
+//! Swift-facing entry points.
+
+use crate::error::{Error, Result};
+use rusqlite::types::ValueRef;
+use rusqlite::Connection;
+use serde::Serialize;
+use serde_json::Value;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// Output format for an export. JSON is always available; YAML and CSV require the
+/// corresponding Cargo feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "csv")]
+    Csv,
+}
+
+impl ExportFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            #[cfg(feature = "yaml")]
+            ExportFormat::Yaml => "yaml",
+            #[cfg(feature = "csv")]
+            ExportFormat::Csv => "csv",
+        }
+    }
+}
+
+/// One unified-database table this module knows how to export: its stable column order (so a
+/// diff between two exports of the same table only shows real changes, not column reshuffling),
+/// and which of those columns hold Unix timestamps and so are rendered as RFC3339 instead of a
+/// raw integer. Mirrors the `CREATE TABLE` column lists in [`crate::schema`].
+pub(crate) struct TableSpec {
+    pub(crate) name: &'static str,
+    pub(crate) columns: &'static [&'static str],
+    pub(crate) timestamp_columns: &'static [&'static str],
+}
+
+pub(crate) const EXPORTABLE_TABLES: &[TableSpec] = &[
+    TableSpec {
+        name: "messages",
+        columns: &[
+            "id",
+            "record_hash",
+            "text",
+            "is_from_me",
+            "timestamp",
+            "date_read",
+            "date_delivered",
+            "handle_id",
+            "chat_id",
+            "service",
+            "has_attachment",
+            "deleted_at",
+        ],
+        timestamp_columns: &["timestamp", "date_read", "date_delivered", "deleted_at"],
+    },
+    TableSpec {
+        name: "web_visits",
+        columns: &[
+            "id",
+            "record_hash",
+            "url",
+            "title",
+            "visit_time",
+            "visit_duration_seconds",
+            "transition_type",
+            "transition",
+            "browser",
+            "profile",
+            "deleted_at",
+        ],
+        timestamp_columns: &["visit_time", "deleted_at"],
+    },
+    TableSpec {
+        name: "app_usage",
+        columns: &[
+            "id",
+            "record_hash",
+            "bundle_id",
+            "start_time",
+            "end_time",
+            "duration_seconds",
+            "device_id",
+            "device_model",
+            "source_db",
+            "deleted_at",
+        ],
+        timestamp_columns: &["start_time", "end_time", "deleted_at"],
+    },
+    TableSpec {
+        name: "podcast_episodes",
+        columns: &[
+            "id",
+            "record_hash",
+            "episode_title",
+            "show_title",
+            "show_uuid",
+            "duration_seconds",
+            "played_seconds",
+            "play_count",
+            "last_played_at",
+            "published_at",
+            "guid",
+            "enclosure_url",
+            "description",
+            "show_notes",
+            "deleted_at",
+        ],
+        timestamp_columns: &["last_played_at", "published_at", "deleted_at"],
+    },
+];
+
+/// Serialize `value` to `path` in the requested format.
+///
+/// `format` must be [`ExportFormat::Json`] or (with the `yaml` feature) [`ExportFormat::Yaml`];
+/// [`ExportFormat::Csv`] only makes sense for the row-oriented data in
+/// [`export_database`], not an arbitrary serializable value, and is rejected here.
+pub fn export_value<T: Serialize>(value: &T, format: ExportFormat, path: &Path) -> Result<()> {
+    match format {
+        ExportFormat::Json => {
+            let file = create_file(path)?;
+            serde_json::to_writer_pretty(file, value)
+                .map_err(|e| Error::ExtractionFailed(format!("Failed to write JSON export: {}", e)))
+        }
+        #[cfg(feature = "yaml")]
+        ExportFormat::Yaml => {
+            let file = create_file(path)?;
+            serde_yaml::to_writer(file, value)
+                .map_err(|e| Error::ExtractionFailed(format!("Failed to write YAML export: {}", e)))
+        }
+        #[cfg(feature = "csv")]
+        ExportFormat::Csv => Err(Error::ExtractionFailed(
+            "CSV export only supports table data (see export_database), not a single report value"
+                .to_string(),
+        )),
+    }
+}
+
+/// Export every table in [`EXPORTABLE_TABLES`] from the unified database at
+/// `output_dir/unified.db` to its own file under `dest_dir`, named `<table>.<extension>`.
+/// Returns the paths written, one per table, in the same order as [`EXPORTABLE_TABLES`].
+///
+/// `tables`, if `Some`, restricts this to the named subset (see
+/// [`crate::types::ExtractionConfig::export_tables`]); an unrecognized name is ignored rather
+/// than treated as an error, since a caller filtering by [`crate::types::CollectorType::unified_tables`]
+/// may list a table this crate version doesn't export yet.
+///
+/// `encryption_key` must match whatever the database was extracted with (`None` for a
+/// plaintext database), so it can be decrypted the same way extraction did before reading.
+pub fn export_database(
+    output_dir: &Path,
+    format: ExportFormat,
+    dest_dir: &Path,
+    tables: Option<&[String]>,
+    encryption_key: Option<&str>,
+) -> Result<Vec<PathBuf>> {
+    let db_path = output_dir.join("unified.db");
+    if !db_path.exists() {
+        return Err(Error::database_context(
+            "export_database",
+            db_path.display().to_string(),
+            "database does not exist",
+            "Run an extraction first to create unified.db",
+        ));
+    }
+
+    std::fs::create_dir_all(dest_dir)
+        .map_err(|e| Error::io_context("export_database", dest_dir.display().to_string(), e))?;
+
+    crate::with_unified_db(output_dir, encryption_key, |conn| {
+        let mut written = Vec::with_capacity(EXPORTABLE_TABLES.len());
+        for spec in selected_tables(tables) {
+            let dest = dest_dir.join(format!("{}.{}", spec.name, format.extension()));
+            export_table(conn, spec, format, &dest)?;
+            written.push(dest);
+        }
+
+        Ok(written)
+    })
+}
+
+/// Filter [`EXPORTABLE_TABLES`] down to `tables` (by name) when given, or return all of them.
+pub(crate) fn selected_tables(tables: Option<&[String]>) -> Vec<&'static TableSpec> {
+    match tables {
+        Some(names) => EXPORTABLE_TABLES
+            .iter()
+            .filter(|spec| names.iter().any(|n| n == spec.name))
+            .collect(),
+        None => EXPORTABLE_TABLES.iter().collect(),
+    }
+}
+
+fn export_table(
+    conn: &Connection,
+    spec: &TableSpec,
+    format: ExportFormat,
+    dest: &Path,
+) -> Result<()> {
+    let sql = format!("SELECT {} FROM {}", spec.columns.join(", "), spec.name);
+    let mut stmt = conn.prepare(&sql)?;
+    let mut rows = stmt.query([])?;
+
+    match format {
+        ExportFormat::Json => {
+            let mut table = Vec::new();
+            while let Some(row) = rows.next()? {
+                table.push(row_to_json_object(row, spec));
+            }
+            let file = create_file(dest)?;
+            serde_json::to_writer_pretty(file, &table).map_err(|e| {
+                Error::ExtractionFailed(format!("Failed to write {} export: {}", spec.name, e))
+            })
+        }
+        #[cfg(feature = "yaml")]
+        ExportFormat::Yaml => {
+            let mut table = Vec::new();
+            while let Some(row) = rows.next()? {
+                table.push(row_to_json_object(row, spec));
+            }
+            let file = create_file(dest)?;
+            serde_yaml::to_writer(file, &table).map_err(|e| {
+                Error::ExtractionFailed(format!("Failed to write {} export: {}", spec.name, e))
+            })
+        }
+        #[cfg(feature = "csv")]
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_path(dest).map_err(|e| {
+                Error::ExtractionFailed(format!(
+                    "Failed to open {} for CSV export: {}",
+                    dest.display(),
+                    e
+                ))
+            })?;
+
+            writer
+                .write_record(spec.columns.iter().copied())
+                .map_err(|e| Error::ExtractionFailed(e.to_string()))?;
+
+            while let Some(row) = rows.next()? {
+                let record: Vec<String> = spec
+                    .columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, col)| csv_cell(row, i, spec.timestamp_columns.contains(col)))
+                    .collect();
+                writer
+                    .write_record(&record)
+                    .map_err(|e| Error::ExtractionFailed(e.to_string()))?;
+            }
+
+            writer
+                .flush()
+                .map_err(|e| Error::ExtractionFailed(e.to_string()))
+        }
+    }
+}
+
+fn create_file(path: &Path) -> Result<File> {
+    File::create(path).map_err(|e| Error::io_context("export", path.display().to_string(), e))
+}
+
+fn row_to_json_object(row: &rusqlite::Row<'_>, spec: &TableSpec) -> Value {
+    let mut map = serde_json::Map::new();
+    for (i, col) in spec.columns.iter().enumerate() {
+        let value = if spec.timestamp_columns.contains(col) {
+            timestamp_cell_json(row, i)
+        } else {
+            row_value_to_json(row, i)
+        };
+        map.insert((*col).to_string(), value);
+    }
+    Value::Object(map)
+}
+
+/// Render an integer Unix-timestamp column as an RFC3339 string, or `null` if the column is
+/// NULL or isn't a valid timestamp.
+fn timestamp_cell_json(row: &rusqlite::Row<'_>, idx: usize) -> Value {
+    match row.get::<_, Option<i64>>(idx) {
+        Ok(Some(unix)) => chrono::DateTime::from_timestamp(unix, 0)
+            .map(|dt| Value::from(dt.to_rfc3339()))
+            .unwrap_or(Value::Null),
+        _ => Value::Null,
+    }
+}
+
+fn csv_cell(row: &rusqlite::Row<'_>, idx: usize, is_timestamp: bool) -> String {
+    if is_timestamp {
+        return match timestamp_cell_json(row, idx) {
+            Value::String(s) => s,
+            _ => String::new(),
+        };
+    }
+
+    match row.get_ref(idx) {
+        Ok(ValueRef::Null) | Err(_) => String::new(),
+        Ok(ValueRef::Integer(i)) => i.to_string(),
+        Ok(ValueRef::Real(f)) => f.to_string(),
+        Ok(ValueRef::Text(t)) => String::from_utf8_lossy(t).into_owned(),
+        Ok(ValueRef::Blob(_)) => String::new(),
+    }
+}
+
+/// Read a column generically by its SQLite type affinity, for tables whose columns are
+/// otherwise untyped from this module's point of view.
+fn row_value_to_json(row: &rusqlite::Row<'_>, idx: usize) -> Value {
+    match row.get_ref(idx) {
+        Ok(ValueRef::Null) | Err(_) => Value::Null,
+        Ok(ValueRef::Integer(i)) => Value::from(i),
+        Ok(ValueRef::Real(f)) => Value::from(f),
+        Ok(ValueRef::Text(t)) => Value::from(String::from_utf8_lossy(t).into_owned()),
+        Ok(ValueRef::Blob(_)) => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+    use std::fs;
+
+    #[test]
+    fn test_export_database_writes_one_json_file_per_table() {
+        let dir = std::env::temp_dir().join(format!(
+            "quantified-core-export-test-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        let output_dir = dir.join("out");
+        fs::create_dir_all(&output_dir).unwrap();
+
+        let conn = Connection::open(output_dir.join("unified.db")).unwrap();
+        schema::init_database(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO messages (record_hash, text, is_from_me, timestamp) VALUES (?, ?, ?, ?)",
+            rusqlite::params!["hash1", "hello", 1, 1_672_531_200i64],
+        )
+        .unwrap();
+        drop(conn);
+
+        let dest_dir = dir.join("export");
+        let written =
+            export_database(&output_dir, ExportFormat::Json, &dest_dir, None, None).unwrap();
+
+        assert_eq!(written.len(), EXPORTABLE_TABLES.len());
+        let messages_json = fs::read_to_string(dest_dir.join("messages.json")).unwrap();
+        let parsed: Value = serde_json::from_str(&messages_json).unwrap();
+        let rows = parsed.as_array().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0]["text"], "hello");
+        assert_eq!(rows[0]["timestamp"], "2023-01-01T00:00:00+00:00");
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
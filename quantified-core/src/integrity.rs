@@ -0,0 +1,164 @@
+//! Forensic source-integrity layer: read-only/immutable opens, a pre-extraction checksum
+//! verification, and per-source chain-of-custody records.
+//!
+//! Extraction sources (knowledgeC.db, chat.db, Chrome's History, ...) may themselves be evidence
+//! in an investigation, so a collector must never write to them and should be able to prove that
+//! it didn't. This mirrors the approach `bupstash`'s query cache takes toward its own SQLite
+//! files: open read-only and immutable, run an integrity check before trusting the contents, and
+//! record a checksum of exactly what was read. [`collectors::base`] wires this into the default
+//! extraction pipeline; [`super::storage::StorageBackend::record_source_provenance`] persists the
+//! result.
+
+use crate::error::{Error, Result};
+use rusqlite::{Connection, OpenFlags};
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// Open `path` read-only and immutable via SQLite's `immutable=1` URI parameter: SQLite will not
+/// acquire any locks and assumes the file never changes underneath it, which both protects a
+/// source that might be evidence and lets SQLite skip its usual lock/rollback-journal
+/// bookkeeping.
+pub fn open_source_readonly(path: &Path) -> Result<Connection> {
+    let uri = format!("file:{}?immutable=1", path.display());
+
+    Connection::open_with_flags(
+        uri,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_URI,
+    )
+    .map_err(|e| {
+        Error::database_context(
+            "open_source_readonly",
+            path.display().to_string(),
+            e,
+            "Ensure the source database file exists and is readable",
+        )
+    })
+}
+
+/// Open `path` read-only via SQLite's `mode=ro&immutable=1` URI, for use as the source side of
+/// an Online Backup snapshot (see `collectors::base::copy_source_db`). Unlike
+/// [`open_source_readonly`], this is meant to be pointed at a live, possibly WAL-mode
+/// application database rather than a copy already made for extraction.
+pub fn open_source_for_backup(path: &Path) -> Result<Connection> {
+    let uri = format!("file:{}?mode=ro&immutable=1", path.display());
+
+    Connection::open_with_flags(
+        uri,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_URI,
+    )
+    .map_err(|e| {
+        Error::database_context(
+            "open_source_for_backup",
+            path.display().to_string(),
+            e,
+            "Ensure the source database file exists and is readable",
+        )
+    })
+}
+
+/// Run `PRAGMA quick_check` against an open connection, returning `"ok"` if it passed or the
+/// first reported problem otherwise. `quick_check` skips the (much slower) index cross-checks
+/// that full `integrity_check` does, which is the right tradeoff for a check run on every
+/// extraction rather than on demand.
+pub fn quick_check(conn: &Connection) -> Result<String> {
+    let result: String = conn.query_row("PRAGMA quick_check", [], |row| row.get(0))?;
+    Ok(result)
+}
+
+/// Run a full `PRAGMA integrity_check` against `path` (opened read-only) — the slower,
+/// index-cross-checking counterpart to [`quick_check`], suitable for on-demand verification of
+/// an already-copied database rather than a check run on every extraction. Returns
+/// [`Error::Corruption`] with every failing row (`integrity_check` can report more than one)
+/// when the result isn't a single `ok` row.
+pub fn verify_integrity(path: &Path) -> Result<()> {
+    let conn = open_source_readonly(path)?;
+    let rows: Vec<String> = conn
+        .prepare("PRAGMA integrity_check")?
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    if rows.len() == 1 && rows[0] == "ok" {
+        return Ok(());
+    }
+
+    Err(Error::Corruption {
+        database: path.to_path_buf(),
+        detail: "integrity_check reported problems".to_string(),
+        check_output: Some(rows.join("\n")),
+    })
+}
+
+/// Re-hash `path` and compare it against `expected` (a digest taken earlier, e.g. right before a
+/// copy began), to catch mid-copy corruption that produced a byte-valid but wrong file —
+/// something `integrity_check` alone can't see if the torn read still happens to parse as valid
+/// SQLite pages.
+pub fn verify_checksum(path: &Path, expected: &str) -> Result<()> {
+    let actual = sha256_file(path)?;
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(Error::ChecksumMismatch {
+            path: path.to_path_buf(),
+            expected: expected.to_string(),
+            actual,
+        })
+    }
+}
+
+/// Compute the SHA-256 of a file on disk, streaming it in chunks so the whole file doesn't need
+/// to live in memory at once.
+pub fn sha256_file(path: &Path) -> Result<String> {
+    let mut file = File::open(path)
+        .map_err(|e| Error::io_context("sha256_file", path.display().to_string(), e))?;
+
+    let mut hasher = Sha256::new();
+    let mut buf = [0u8; 64 * 1024];
+    loop {
+        let read = file
+            .read(&mut buf)
+            .map_err(|e| Error::io_context("sha256_file", path.display().to_string(), e))?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buf[..read]);
+    }
+
+    Ok(hex::encode(hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_sha256_file_matches_known_digest() {
+        let mut file = NamedTempFile::new().unwrap();
+        file.write_all(b"hello world").unwrap();
+
+        let digest = sha256_file(file.path()).unwrap();
+
+        assert_eq!(
+            digest,
+            "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+        );
+    }
+
+    #[test]
+    fn test_open_source_readonly_and_quick_check_ok() {
+        let file = NamedTempFile::new().unwrap();
+        {
+            let conn = Connection::open(file.path()).unwrap();
+            conn.execute_batch("CREATE TABLE t (a INTEGER)").unwrap();
+        }
+
+        let conn = open_source_readonly(file.path()).unwrap();
+        assert_eq!(quick_check(&conn).unwrap(), "ok");
+
+        // The connection is genuinely read-only: writes must fail.
+        assert!(conn.execute_batch("INSERT INTO t VALUES (1)").is_err());
+    }
+}
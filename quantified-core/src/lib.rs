@@ -17,25 +17,53 @@
 //! }
 //! ```
 
+#[cfg(feature = "arrow")]
+pub mod arrow_export;
+#[cfg(feature = "session")]
+pub mod changeset;
 pub mod collectors;
+pub mod crypto;
 pub mod error;
+pub mod export;
+pub mod integrity;
+pub mod maintenance;
+pub mod metrics;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod progress;
+pub mod retry;
+pub mod row;
 pub mod schema;
+pub mod search;
+pub mod storage;
 pub mod timestamp;
+#[cfg(feature = "tracing")]
+pub mod tracing_support;
 pub mod types;
 pub mod uniffi_api;
+pub mod watch;
 
 pub use error::{Error, Result};
 pub use types::{CollectorType, ExtractionConfig as CoreExtractionConfig, ExtractionResult};
+pub use watch::watch;
 
 // Re-export uniffi API for Swift integration
+#[cfg(feature = "arrow")]
+pub use uniffi_api::write_parquet;
+#[cfg(feature = "rss")]
+pub use uniffi_api::{download_podcast_episodes, DownloadSummary};
 pub use uniffi_api::{
-    DataSourceInfo, DataSourceType, ExtractionConfig as SwiftExtractionConfig,
-    ExtractionReport, SourceResult, DatabaseStats, ExtractionError,
-    scan_data_sources, extract_all_data, extract_single_source, get_database_stats,
+    export_database, export_report, extract_all_data, extract_single_source, get_database_stats,
+    get_top_sites, run_maintenance, scan_data_sources, search, DataSourceInfo, DataSourceType,
+    DatabaseStats, ExportFormat as SwiftExportFormat, ExtractionConfig as SwiftExtractionConfig,
+    ExtractionError, ExtractionHandle, ExtractionObserver, ExtractionReport, MaintenanceMetrics,
+    SearchHit, SiteFrecency, SourceResult, WebVisitTransition,
 };
 
 use rusqlite::Connection;
-use std::path::PathBuf;
+#[cfg(feature = "session")]
+use rusqlite::OptionalExtension;
+use std::path::{Path, PathBuf};
 
 // uniffi scaffolding - must be at crate root for proc-macro approach
 uniffi::setup_scaffolding!();
@@ -57,30 +85,212 @@ pub fn extract_all(config: &CoreExtractionConfig) -> Result<Vec<ExtractionResult
     Ok(results)
 }
 
+/// Extract data from all available collectors concurrently instead of one at a time.
+///
+/// Each collector already opens and owns its own `Connection` to `unified.db` for the
+/// lifetime of a single [`extract_source`] call (SQLite's own locking serializes writers at
+/// the file level), so the copy/integrity-check/extract work for Messages, Chrome, and
+/// Podcasts — all I/O-bound and independent of one another — can simply run on their own
+/// thread rather than waiting in turn. This stops short of the `Box<dyn Collector + Send>`
+/// thread-pool design of taking a pre-built collector per job: every `Collector` impl still
+/// borrows its `unified_db`/`config` for a lifetime tied to one `run()` call, and generalizing
+/// that ownership to be handed across a pool is the larger, separate change `extract_source`'s
+/// existing "open a connection, build a collector, run it, close it" shape was chosen to avoid.
+/// Spawning one thread per [`CollectorType`] that each drive their own `extract_source` call
+/// gets the same wall-clock win without touching that model.
+///
+/// In fallback (non-SQLCipher) envelope-encryption mode, [`open_unified_db`]/[`finalize_unified_db`]
+/// decrypt `unified.db` to plaintext in place before the first collector touches it and re-encrypt
+/// it in place once the last one finishes — there's only one `unified.db` path, so two collector
+/// threads each doing that concurrently would race and corrupt the file. SQLCipher doesn't have
+/// this problem (each connection applies `PRAGMA key` itself; the file on disk never leaves
+/// ciphertext), so this only falls back to sequential extraction when envelope encryption is
+/// actually in play.
+pub fn extract_all_parallel(config: &CoreExtractionConfig) -> Result<Vec<ExtractionResult>> {
+    #[cfg(not(feature = "sqlcipher"))]
+    if config.encryption_key.is_some() {
+        return extract_all(config);
+    }
+
+    let mut results = Vec::new();
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = CollectorType::all()
+            .into_iter()
+            .map(|collector_type| {
+                scope.spawn(move || (collector_type, extract_source(config, collector_type)))
+            })
+            .collect();
+
+        for handle in handles {
+            let (collector_type, result) = handle.join().expect("collector thread panicked");
+            match result {
+                Ok(result) => results.push(result),
+                Err(e) => {
+                    eprintln!("Failed to extract {}: {}", collector_type.name(), e);
+                    // Continue with other collectors
+                }
+            }
+        }
+    });
+
+    Ok(results)
+}
+
 /// Extract data from a specific collector
 pub fn extract_source(
     config: &CoreExtractionConfig,
     collector_type: CollectorType,
 ) -> Result<ExtractionResult> {
+    #[cfg(feature = "otel")]
+    if let Some(endpoint) = &config.otel_endpoint {
+        otel::init(endpoint)?;
+    }
+
     // Open or create unified database
-    let unified_db = open_unified_db(&config.output_dir)?;
+    let unified_db = open_unified_db(config)?;
 
     // Create collector and run extraction
     let mut collector = collectors::create_collector(collector_type, config, &unified_db)?;
-    collector.run()
+
+    #[cfg(feature = "session")]
+    let result = if config.capture_changesets {
+        let (result, changeset_bytes) = changeset::capture(&unified_db, || collector.run())?;
+        if !changeset_bytes.is_empty() {
+            if let Some(run_id) = latest_run_id(&unified_db, collector_type.name())? {
+                changeset::record(&unified_db, run_id, &changeset_bytes)?;
+            }
+        }
+        Ok(result)
+    } else {
+        collector.run()
+    };
+    #[cfg(not(feature = "session"))]
+    let result = collector.run();
+
+    drop(collector);
+    finalize_unified_db(config, unified_db)?;
+
+    result
 }
 
-/// Open the unified database, creating it if it doesn't exist
-pub fn open_unified_db(output_dir: &PathBuf) -> Result<Connection> {
-    let db_path = output_dir.join("unified.db");
-    let conn = Connection::open(&db_path)?;
+/// Id of the most recently started `extraction_runs` row for `source`, used to key a freshly
+/// captured changeset to the run that produced it.
+#[cfg(feature = "session")]
+fn latest_run_id(conn: &Connection, source: &str) -> Result<Option<i64>> {
+    conn.query_row(
+        "SELECT id FROM extraction_runs WHERE source = ? ORDER BY id DESC LIMIT 1",
+        rusqlite::params![source],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(Into::into)
+}
+
+/// Open the unified database, creating it if it doesn't exist.
+///
+/// If `config.encryption_key` is set, the database is opened encrypted: transparently via
+/// SQLCipher pragmas when the crate is built with the `sqlcipher` feature, or by decrypting
+/// an existing AES-256-GCM envelope to a plaintext working copy otherwise (re-sealed by
+/// [`finalize_unified_db`] once the caller is done with the connection).
+///
+/// Enables WAL mode and a generous busy timeout so that [`extract_all_parallel`]'s
+/// one-connection-per-collector threads can each hold their own write transaction without
+/// immediately tripping `SQLITE_BUSY` against each other.
+pub fn open_unified_db(config: &CoreExtractionConfig) -> Result<Connection> {
+    let db_path = config.output_dir.join("unified.db");
+
+    let conn = match &config.encryption_key {
+        Some(passphrase) => open_encrypted_unified_db(&db_path, passphrase)?,
+        None => Connection::open(&db_path)?,
+    };
+
+    conn.busy_timeout(std::time::Duration::from_secs(30))?;
+    conn.pragma_update(None, "journal_mode", "WAL")?;
 
     // Initialize schema if needed
     schema::init_database(&conn)?;
+    search::ensure_search_indexes(&conn)?;
 
     Ok(conn)
 }
 
+#[cfg(feature = "sqlcipher")]
+fn open_encrypted_unified_db(db_path: &PathBuf, passphrase: &str) -> Result<Connection> {
+    let conn = Connection::open(db_path)?;
+    crypto::apply_sqlcipher_pragmas(&conn, passphrase)?;
+    Ok(conn)
+}
+
+#[cfg(not(feature = "sqlcipher"))]
+fn open_encrypted_unified_db(db_path: &PathBuf, passphrase: &str) -> Result<Connection> {
+    let key = crypto::derive_key(passphrase);
+
+    if db_path.exists() && crypto::looks_like_envelope(db_path) {
+        let temp = crypto::decrypt_file_to_temp(db_path, &key)?;
+        std::fs::rename(&temp, db_path).map_err(|e| {
+            Error::io_context(
+                "open_encrypted_unified_db",
+                db_path.display().to_string(),
+                e,
+            )
+        })?;
+    }
+
+    Ok(Connection::open(db_path)?)
+}
+
+/// Close out the unified database connection opened by [`open_unified_db`], re-sealing it
+/// into an AES-256-GCM envelope when running in fallback (non-SQLCipher) encryption mode.
+pub(crate) fn finalize_unified_db(config: &CoreExtractionConfig, conn: Connection) -> Result<()> {
+    #[cfg(not(feature = "sqlcipher"))]
+    if let Some(passphrase) = &config.encryption_key {
+        // WAL mode (enabled by `open_unified_db`) keeps recently-written pages in a separate
+        // `-wal` file (plus a `-shm` index over it) until a checkpoint folds them back into the
+        // main file; encrypting just `unified.db` below would leave those sidecar files as
+        // plaintext on disk, defeating the at-rest confidentiality this is supposed to provide.
+        // Switching back to DELETE journal mode forces a full checkpoint and removes both.
+        conn.pragma_update(None, "journal_mode", "DELETE")?;
+        drop(conn);
+
+        let db_path = config.output_dir.join("unified.db");
+        let key = crypto::derive_key(passphrase);
+        crypto::encrypt_file_in_place(&db_path, &key)?;
+        return Ok(());
+    }
+
+    drop(conn);
+
+    #[cfg(feature = "sqlcipher")]
+    let _ = config;
+
+    Ok(())
+}
+
+/// Open `output_dir/unified.db` for a non-extraction operation (stats, search, export,
+/// maintenance) that still needs [`open_unified_db`]'s decrypt-on-open /
+/// [`finalize_unified_db`]'s re-seal-on-close handling whenever `encryption_key` is set —
+/// every one of these used to open the file directly with a bare `Connection::open`, which made
+/// an encrypted `unified.db` unreadable to anything but [`extract_source`] itself. `f` runs
+/// against the (already decrypted, if applicable) connection; the database is re-sealed once
+/// `f` returns, whether or not it succeeded, the same shape [`extract_source`] already uses
+/// around a collector's `run()`.
+pub(crate) fn with_unified_db<R>(
+    output_dir: &Path,
+    encryption_key: Option<&str>,
+    f: impl FnOnce(&Connection) -> Result<R>,
+) -> Result<R> {
+    let mut config = CoreExtractionConfig::with_output_dir(output_dir.to_path_buf());
+    if let Some(key) = encryption_key {
+        config = config.with_encryption_key(key);
+    }
+
+    let conn = open_unified_db(&config)?;
+    let result = f(&conn);
+    finalize_unified_db(&config, conn)?;
+    result
+}
+
 /// Check if a source database exists at any of the given paths
 pub fn find_source_db(paths: &[String]) -> Option<PathBuf> {
     for path in paths {
@@ -100,37 +310,30 @@ fn get_legacy_database_stats(output_dir: &PathBuf) -> Result<LegacyDatabaseStats
         return Err(Error::DatabaseNotFound(db_path));
     }
 
-    let conn = Connection::open(&db_path)?;
-
-    let app_usage_count: i64 = conn.query_row(
-        "SELECT COUNT(*) FROM app_usage",
-        [],
-        |row| row.get(0),
-    )?;
+    // No encryption key reaches this internal helper (it has no caller that has one to give
+    // it), so this can only ever read a plaintext `unified.db`; see [`with_unified_db`] for the
+    // decrypt/re-seal path a caller that does have a key needs to go through instead.
+    with_unified_db(output_dir, None, |conn| {
+        let app_usage_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM app_usage", [], |row| row.get(0))?;
 
-    let web_visits_count: i64 = conn.query_row(
-        "SELECT COUNT(*) FROM web_visits",
-        [],
-        |row| row.get(0),
-    )?;
+        let web_visits_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM web_visits", [], |row| row.get(0))?;
 
-    let messages_count: i64 = conn.query_row(
-        "SELECT COUNT(*) FROM messages",
-        [],
-        |row| row.get(0),
-    )?;
+        let messages_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM messages", [], |row| row.get(0))?;
 
-    let podcast_episodes_count: i64 = conn.query_row(
-        "SELECT COUNT(*) FROM podcast_episodes",
-        [],
-        |row| row.get(0),
-    )?;
+        let podcast_episodes_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM podcast_episodes", [], |row| {
+                row.get(0)
+            })?;
 
-    Ok(LegacyDatabaseStats {
-        app_usage_count: app_usage_count as usize,
-        web_visits_count: web_visits_count as usize,
-        messages_count: messages_count as usize,
-        podcast_episodes_count: podcast_episodes_count as usize,
+        Ok(LegacyDatabaseStats {
+            app_usage_count: app_usage_count as usize,
+            web_visits_count: web_visits_count as usize,
+            messages_count: messages_count as usize,
+            podcast_episodes_count: podcast_episodes_count as usize,
+        })
     })
 }
 
@@ -156,7 +359,7 @@ mod tests {
             ..Default::default()
         };
 
-        let conn = open_unified_db(&config.output_dir).unwrap();
+        let conn = open_unified_db(&config).unwrap();
 
         // Verify schema was created
         let table_count: i64 = conn
@@ -169,6 +372,4 @@ mod tests {
 
         assert!(table_count > 0, "Database should have tables");
     }
-
-
 }
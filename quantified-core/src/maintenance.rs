@@ -0,0 +1,175 @@
+//! Periodic housekeeping for the unified database.
+//!
+//! Each extraction run already skips exact duplicates via `record_hash` (see
+//! [`crate::collectors::base::BaseCollector::insert_dedup`]), but rows that represent the same
+//! real-world record under a table's natural key can still end up with distinct hashes — the
+//! same web visit recorded by two browser profiles, the same app-usage session re-derived with
+//! a slightly different device id, and so on. [`run_maintenance`] removes those, rebuilds
+//! indexes, and reclaims space, modeled on the `run_maintenance`/`RunMaintenanceMetrics`
+//! facility of mature history stores. See [`crate::uniffi_api::run_maintenance`] for the
+//! Swift-facing entry point.
+
+use crate::error::Result;
+use rusqlite::Connection;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Instant;
+
+/// One table this module knows how to de-duplicate, and the natural-key columns that identify
+/// "the same real-world record". For `messages` that is `record_hash` itself, which the messages
+/// collector fills with the message GUID.
+struct DedupSpec {
+    table: &'static str,
+    natural_key: &'static [&'static str],
+}
+
+const DEDUP_TABLES: &[DedupSpec] = &[
+    DedupSpec {
+        table: "messages",
+        natural_key: &["record_hash"],
+    },
+    DedupSpec {
+        table: "web_visits",
+        natural_key: &["url", "visit_time"],
+    },
+    DedupSpec {
+        table: "app_usage",
+        natural_key: &["bundle_id", "start_time"],
+    },
+    DedupSpec {
+        table: "podcast_episodes",
+        natural_key: &["guid"],
+    },
+];
+
+/// Result of a [`run_maintenance`] pass.
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceMetrics {
+    /// Rows removed per table (keyed by table name) because another row already matched that
+    /// table's natural key.
+    pub rows_deduplicated: HashMap<String, usize>,
+    /// Free-list pages reclaimed by the `VACUUM` pass.
+    pub pages_vacuumed: i64,
+    /// Bytes the database file shrank by as a result of vacuuming.
+    pub bytes_reclaimed: i64,
+    pub duration_seconds: f64,
+}
+
+/// Run a full maintenance pass against the unified database at `output_dir/unified.db`:
+/// de-duplicate each table in [`DEDUP_TABLES`] by natural key, rebuild indexes, then `ANALYZE`
+/// and `VACUUM` to reclaim space and keep the query planner's statistics fresh. Safe to call
+/// periodically after repeated extraction runs.
+///
+/// `encryption_key` must match whatever the database was extracted with (`None` for a
+/// plaintext database), so it can be decrypted before maintenance and re-sealed afterwards the
+/// same way extraction does.
+pub fn run_maintenance(
+    output_dir: &Path,
+    encryption_key: Option<&str>,
+) -> Result<MaintenanceMetrics> {
+    let started = Instant::now();
+    let db_path = output_dir.join("unified.db");
+    let size_before = std::fs::metadata(&db_path).map(|m| m.len()).unwrap_or(0);
+
+    let (rows_deduplicated, pages_vacuumed) =
+        crate::with_unified_db(output_dir, encryption_key, |conn| {
+            let mut rows_deduplicated = HashMap::with_capacity(DEDUP_TABLES.len());
+            for spec in DEDUP_TABLES {
+                let deleted = dedup_table(conn, spec)?;
+                rows_deduplicated.insert(spec.table.to_string(), deleted);
+            }
+
+            conn.execute_batch("REINDEX")?;
+            conn.execute_batch("ANALYZE")?;
+
+            let page_count_before: i64 =
+                conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+            // This schema doesn't run in incremental auto_vacuum mode, so a full VACUUM is what
+            // actually reclaims the pages freed by the de-duplication above; it also rewrites the
+            // file compactly the same way an incremental vacuum would over several passes.
+            conn.execute_batch("VACUUM")?;
+            let page_count_after: i64 =
+                conn.query_row("PRAGMA page_count", [], |row| row.get(0))?;
+
+            Ok((
+                rows_deduplicated,
+                (page_count_before - page_count_after).max(0),
+            ))
+        })?;
+
+    let size_after = std::fs::metadata(&db_path)
+        .map(|m| m.len())
+        .unwrap_or(size_before);
+
+    Ok(MaintenanceMetrics {
+        rows_deduplicated,
+        pages_vacuumed,
+        bytes_reclaimed: (size_before.saturating_sub(size_after)) as i64,
+        duration_seconds: started.elapsed().as_secs_f64(),
+    })
+}
+
+/// Delete every row in `spec.table` except the lowest-`id` row per natural-key group. Rows with
+/// a NULL natural-key column are left untouched, since SQLite's `GROUP BY` would otherwise treat
+/// all of them as a single group and collapse unrelated rows together.
+fn dedup_table(conn: &Connection, spec: &DedupSpec) -> Result<usize> {
+    let keys = spec.natural_key.join(", ");
+    let not_null = spec
+        .natural_key
+        .iter()
+        .map(|col| format!("{} IS NOT NULL", col))
+        .collect::<Vec<_>>()
+        .join(" AND ");
+
+    let sql = format!(
+        "DELETE FROM {table} \
+         WHERE {not_null} \
+         AND id NOT IN (SELECT MIN(id) FROM {table} WHERE {not_null} GROUP BY {keys})",
+        table = spec.table,
+        not_null = not_null,
+        keys = keys,
+    );
+
+    Ok(conn.execute(&sql, [])?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+
+    #[test]
+    fn test_run_maintenance_dedups_by_natural_key() {
+        let dir = std::env::temp_dir().join(format!(
+            "quantified-core-maintenance-test-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let conn = Connection::open(dir.join("unified.db")).unwrap();
+        schema::init_database(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO web_visits (record_hash, url, visit_time) VALUES (?, ?, ?)",
+            rusqlite::params!["hash1", "https://example.com", 1_672_531_200i64],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO web_visits (record_hash, url, visit_time) VALUES (?, ?, ?)",
+            rusqlite::params!["hash2", "https://example.com", 1_672_531_200i64],
+        )
+        .unwrap();
+        drop(conn);
+
+        let metrics = run_maintenance(&dir, None).unwrap();
+        assert_eq!(metrics.rows_deduplicated.get("web_visits"), Some(&1));
+
+        let conn = Connection::open(dir.join("unified.db")).unwrap();
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM web_visits", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+        drop(conn);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,106 @@
+//! Progress reporting and cooperative cancellation for long-running extractions.
+//!
+//! A multi-gigabyte `chat.db` or years of Chrome history can take minutes to extract, which is
+//! painful for a host UI that can only show "working..." until [`crate::extract_all`] returns.
+//! [`ExtractionProgress`] carries optional callbacks a caller can set on
+//! [`crate::types::ExtractionConfig`] to hear about a run's progress as it happens, plus a
+//! [`CancellationToken`] a caller can flip from another thread to abort a run partway through.
+//! Collectors check in with both via [`crate::collectors::base::BaseCollector::insert_dedup`]
+//! and [`crate::collectors::base::Collector::run`]; see those for where reporting actually
+//! happens. The uniffi-facing equivalents (`ExtractionObserver` callback interface,
+//! `ExtractionHandle` object) live in [`crate::uniffi_api`], which translates between Swift
+//! callbacks and the plain closures here.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A cheaply-cloneable flag a caller can set from another thread (or another callback) to ask a
+/// running extraction to stop at its next checkpoint. Checked, not enforced: a collector only
+/// actually stops the next time it calls [`CancellationToken::is_cancelled`], which in practice
+/// means the next [`crate::collectors::base::BaseCollector::insert_dedup`] batch boundary or the
+/// next source database in a multi-source collector.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a new, not-yet-cancelled token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask the run holding this token to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether [`CancellationToken::cancel`] has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+type SourceStartedCallback = Arc<dyn Fn(&str) + Send + Sync>;
+type ProgressCallback = Arc<dyn Fn(&str, usize, Option<usize>) + Send + Sync>;
+type SourceFinishedCallback = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Optional progress/cancellation hooks for an extraction run. Every field defaults to `None`, so
+/// attaching this to [`crate::types::ExtractionConfig`] is a no-op until a caller sets one.
+#[derive(Clone, Default)]
+pub struct ExtractionProgress {
+    /// Called once a collector begins reading its source database(s).
+    pub on_source_started: Option<SourceStartedCallback>,
+    /// Called periodically with a source name and how many records have been processed so far
+    /// (`records_added + records_skipped`). The total is `None` when a collector has no cheap way
+    /// to know the row count up front (the common case: most sources are read via a single
+    /// streaming `SELECT`, not counted first).
+    pub on_progress: Option<ProgressCallback>,
+    /// Called once a collector finishes, successfully, with an error, or cancelled.
+    pub on_source_finished: Option<SourceFinishedCallback>,
+    /// Checked between batches; see [`CancellationToken`].
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl ExtractionProgress {
+    pub fn report_started(&self, source: &str) {
+        if let Some(callback) = &self.on_source_started {
+            callback(source);
+        }
+    }
+
+    pub fn report_progress(&self, source: &str, records_done: usize, records_total: Option<usize>) {
+        if let Some(callback) = &self.on_progress {
+            callback(source, records_done, records_total);
+        }
+    }
+
+    pub fn report_finished(&self, source: &str) {
+        if let Some(callback) = &self.on_source_finished {
+            callback(source);
+        }
+    }
+
+    /// Whether the attached [`CancellationToken`], if any, has been cancelled. Always `false`
+    /// when no token is attached.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+    }
+}
+
+// `Fn` trait objects aren't `Debug`, so this can't be derived; the closures themselves are
+// elided and only whether each hook is set is shown, which is all a caller debugging an
+// `ExtractionConfig` actually needs.
+impl fmt::Debug for ExtractionProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractionProgress")
+            .field("on_source_started", &self.on_source_started.is_some())
+            .field("on_progress", &self.on_progress.is_some())
+            .field("on_source_finished", &self.on_source_finished.is_some())
+            .field("cancellation", &self.cancellation)
+            .finish()
+    }
+}
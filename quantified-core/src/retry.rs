@@ -0,0 +1,130 @@
+//! Exponential-backoff-with-full-jitter retry for operations that can hit a transient
+//! [`Error`] — in practice, `SQLITE_BUSY`/`SQLITE_LOCKED` when copying a source database that
+//! another process (e.g. the owning app) has open in WAL mode at the same moment. Fatal errors
+//! (permission denied, source not found, …) are never retried; see [`Error::is_transient`].
+
+use crate::error::{Error, Result};
+use std::thread;
+use std::time::Duration;
+
+/// Tuning knobs for [`retry`]. The defaults retry a handful of times over a couple of seconds,
+/// which is enough for a competing writer to finish its transaction and release the lock
+/// without making a genuinely stuck caller wait indefinitely.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first. `1` disables retrying entirely.
+    pub max_attempts: u32,
+    /// Base delay doubled on each successive attempt before jitter is applied.
+    pub base: Duration,
+    /// Upper bound on the (pre-jitter) backoff delay.
+    pub cap: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        RetryConfig {
+            max_attempts: 5,
+            base: Duration::from_millis(50),
+            cap: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Call `f` up to `config.max_attempts` times, retrying only when it fails with a
+/// [`Error::is_transient`] error. Between attempts (never after the last), sleeps for a
+/// full-jitter exponential backoff delay: `rand(0, min(cap, base * 2^attempt))`. Returns the
+/// first success, the first fatal error, or — if every attempt failed transiently —
+/// [`Error::RetriesExhausted`] describing the last error seen.
+pub fn retry<T>(
+    operation: &str,
+    config: RetryConfig,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt < config.max_attempts && e.is_transient() => {
+                let max_delay = config
+                    .base
+                    .saturating_mul(1 << (attempt - 1))
+                    .min(config.cap);
+                let delay = Duration::from_nanos(
+                    rand::random::<u64>() % (max_delay.as_nanos() as u64).max(1),
+                );
+                thread::sleep(delay);
+            }
+            Err(e) if e.is_transient() => {
+                return Err(Error::RetriesExhausted {
+                    operation: operation.to_string(),
+                    attempts: attempt,
+                    last_error: e.to_string(),
+                })
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_retry_succeeds_after_transient_failures() {
+        let calls = Cell::new(0);
+        let result = retry("test_op", RetryConfig::default(), || {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 {
+                Err(Error::Database(rusqlite::Error::SqliteFailure(
+                    rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+                    None,
+                )))
+            } else {
+                Ok(42)
+            }
+        });
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[test]
+    fn test_retry_gives_up_after_max_attempts() {
+        let calls = Cell::new(0);
+        let config = RetryConfig {
+            max_attempts: 3,
+            base: Duration::from_millis(1),
+            cap: Duration::from_millis(5),
+        };
+        let result: Result<()> = retry("test_op", config, || {
+            calls.set(calls.get() + 1);
+            Err(Error::Database(rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_LOCKED),
+                None,
+            )))
+        });
+
+        assert_eq!(calls.get(), 3);
+        match result {
+            Err(Error::RetriesExhausted { attempts, .. }) => assert_eq!(attempts, 3),
+            other => panic!("expected RetriesExhausted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_retry_does_not_retry_fatal_errors() {
+        let calls = Cell::new(0);
+        let result: Result<()> = retry("test_op", RetryConfig::default(), || {
+            calls.set(calls.get() + 1);
+            Err(Error::PermissionDenied {
+                path: std::path::PathBuf::from("/tmp/source.db"),
+            })
+        });
+
+        assert_eq!(calls.get(), 1);
+        assert!(matches!(result, Err(Error::PermissionDenied { .. })));
+    }
+}
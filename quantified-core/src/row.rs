@@ -0,0 +1,99 @@
+//! Typed row-mapping helpers that turn a failed column coercion into a structured
+//! [`Error::ColumnTypeMismatch`]/[`Error::ValueOutOfRange`] naming the offending table, column,
+//! and (when known) row id — instead of a collector surfacing an opaque rusqlite error, or
+//! [`crate::error::Error::InvalidTimestamp`]'s plain message, that don't say which cell was bad.
+
+use crate::error::{Error, Result};
+use rusqlite::types::{FromSql, Type};
+use rusqlite::Row;
+
+/// Read column `idx` (named `column`, purely for error messages) of `row` as `T`, mapping a
+/// rusqlite type-coercion failure to [`Error::ColumnTypeMismatch`] naming `table`/`column`/
+/// `row_id` instead of an opaque rusqlite error pointing at a bare column index.
+pub fn get_checked<T: FromSql>(
+    row: &Row<'_>,
+    idx: usize,
+    table: &str,
+    column: &str,
+    row_id: Option<i64>,
+) -> Result<T> {
+    row.get(idx).map_err(|e| match e {
+        rusqlite::Error::InvalidColumnType(_, _, actual_type) => Error::ColumnTypeMismatch {
+            table: table.to_string(),
+            column: column.to_string(),
+            expected: std::any::type_name::<T>().to_string(),
+            actual: describe_type(actual_type),
+            row_id,
+        },
+        other => other.into(),
+    })
+}
+
+fn describe_type(t: Type) -> String {
+    match t {
+        Type::Null => "NULL",
+        Type::Integer => "INTEGER",
+        Type::Real => "REAL",
+        Type::Text => "TEXT",
+        Type::Blob => "BLOB",
+    }
+    .to_string()
+}
+
+/// Narrow an `i64` column value into `u32`, reporting [`Error::ValueOutOfRange`] (naming
+/// `table`/`column`) rather than truncating/wrapping silently when the value doesn't fit.
+pub fn checked_i64_to_u32(value: i64, table: &str, column: &str) -> Result<u32> {
+    u32::try_from(value).map_err(|_| Error::ValueOutOfRange {
+        table: table.to_string(),
+        column: column.to_string(),
+        value,
+        target_type: "u32",
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn test_get_checked_reports_column_type_mismatch() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE t (id INTEGER, date TEXT); INSERT INTO t VALUES (1, 'not-a-number')",
+        )
+        .unwrap();
+
+        let mut stmt = conn.prepare("SELECT id, date FROM t").unwrap();
+        let mut rows = stmt.query([]).unwrap();
+        let row = rows.next().unwrap().unwrap();
+
+        let row_id: Option<i64> = row.get(0).unwrap();
+        let err = get_checked::<i64>(row, 1, "t", "date", row_id).unwrap_err();
+
+        match err {
+            Error::ColumnTypeMismatch {
+                table,
+                column,
+                row_id,
+                ..
+            } => {
+                assert_eq!(table, "t");
+                assert_eq!(column, "date");
+                assert_eq!(row_id, Some(1));
+            }
+            other => panic!("expected ColumnTypeMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_checked_i64_to_u32_reports_value_out_of_range() {
+        let err = checked_i64_to_u32(i64::from(u32::MAX) + 1, "t", "count").unwrap_err();
+        assert!(matches!(err, Error::ValueOutOfRange { .. }));
+    }
+
+    #[test]
+    fn test_checked_i64_to_u32_accepts_in_range_value() {
+        assert_eq!(checked_i64_to_u32(42, "t", "count").unwrap(), 42);
+    }
+}
@@ -14,7 +14,60 @@ CREATE TABLE IF NOT EXISTS extraction_runs (
     source TEXT NOT NULL,
     records_added INTEGER DEFAULT 0,
     records_skipped INTEGER DEFAULT 0,
-    status TEXT DEFAULT 'running'
+    records_deleted INTEGER DEFAULT 0,
+    status TEXT DEFAULT 'running',
+    -- The collector's watermark (see extraction_state below) as of this run's completion, for
+    -- an at-a-glance audit of how far incremental extraction had reached without joining out to
+    -- extraction_state, which only keeps the latest value per collector rather than a history.
+    last_seen_watermark INTEGER
+);
+
+-- Per-collector incremental watermark: the highest source-native timestamp
+-- (Apple-nano, Chrome epoch, etc.) successfully extracted, so a re-run can
+-- query only newer rows instead of re-scanning the whole source database.
+CREATE TABLE IF NOT EXISTS extraction_state (
+    collector TEXT PRIMARY KEY,
+    watermark INTEGER NOT NULL
+);
+
+-- Generic capture for knowledgeC streams that don't need bespoke columns of their own (see
+-- collectors::knowledgec_streams::StreamSpec): screen-time-in-focus, battery/audio-route
+-- history, Siri usage, etc. Structured-metadata extras spill into metadata_json.
+CREATE TABLE IF NOT EXISTS knowledgec_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    record_hash TEXT UNIQUE NOT NULL,
+    stream_name TEXT NOT NULL,
+    value_text TEXT,
+    value_integer INTEGER,
+    value_double REAL,
+    bundle_id TEXT,
+    metadata_json TEXT,
+    start_time INTEGER NOT NULL,
+    end_time INTEGER,
+    duration_seconds REAL,
+    deleted_at INTEGER
+);
+
+-- SQLite session changeset captured during an extraction run (see `extract_source` in lib.rs and
+-- the `changeset` module), so "what changed since last extraction" can be reported and an
+-- erroneous import can be rolled back without rebuilding the whole unified database.
+CREATE TABLE IF NOT EXISTS run_changesets (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id INTEGER NOT NULL,
+    changeset BLOB NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+-- Chain-of-custody record for each source database an extraction run read from:
+-- its path, size, SHA-256 at the time it was read, and the PRAGMA quick_check result.
+CREATE TABLE IF NOT EXISTS source_provenance (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    collector TEXT NOT NULL,
+    path TEXT NOT NULL,
+    size_bytes INTEGER NOT NULL,
+    sha256 TEXT NOT NULL,
+    integrity_result TEXT NOT NULL,
+    extracted_at INTEGER NOT NULL
 );
 
 -- App usage sessions from knowledgeC
@@ -27,10 +80,15 @@ CREATE TABLE IF NOT EXISTS app_usage (
     duration_seconds REAL,
     device_id TEXT,
     device_model TEXT,
-    source_db TEXT DEFAULT 'knowledgeC'
+    source_db TEXT DEFAULT 'knowledgeC',
+    deleted_at INTEGER
 );
 
--- Web browsing from Chrome
+-- Web browsing from Chrome, Firefox, Safari, Brave, and Edge.
+-- `transition_type` is the browser-native label (e.g. Chromium's "auto_subframe" vs Firefox's
+-- "framed_link"); `transition` is the normalized WebVisitTransition category shared across
+-- browsers (see types::WebVisitTransition), so callers can filter "real" navigations without
+-- knowing each browser's own encoding.
 CREATE TABLE IF NOT EXISTS web_visits (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     record_hash TEXT UNIQUE NOT NULL,
@@ -39,7 +97,10 @@ CREATE TABLE IF NOT EXISTS web_visits (
     visit_time INTEGER NOT NULL,
     visit_duration_seconds REAL,
     transition_type TEXT,
-    browser TEXT DEFAULT 'chrome'
+    transition TEXT,
+    browser TEXT DEFAULT 'chrome',
+    profile TEXT,
+    deleted_at INTEGER
 );
 
 -- Bluetooth device connections from knowledgeC
@@ -50,9 +111,12 @@ CREATE TABLE IF NOT EXISTS bluetooth_connections (
     device_address TEXT,
     device_type INTEGER,
     product_id INTEGER,
+    vendor TEXT,
+    model_name TEXT,
     start_time INTEGER NOT NULL,
     end_time INTEGER,
-    duration_seconds REAL
+    duration_seconds REAL,
+    deleted_at INTEGER
 );
 
 -- Notifications from knowledgeC
@@ -61,7 +125,8 @@ CREATE TABLE IF NOT EXISTS notifications (
     record_hash TEXT UNIQUE NOT NULL,
     bundle_id TEXT NOT NULL,
     event_type TEXT,
-    timestamp INTEGER NOT NULL
+    timestamp INTEGER NOT NULL,
+    deleted_at INTEGER
 );
 
 -- Messages (iMessage/SMS)
@@ -76,7 +141,8 @@ CREATE TABLE IF NOT EXISTS messages (
     handle_id TEXT,
     chat_id TEXT,
     service TEXT,
-    has_attachment INTEGER DEFAULT 0
+    has_attachment INTEGER DEFAULT 0,
+    deleted_at INTEGER
 );
 
 -- Message conversations/chats
@@ -86,7 +152,8 @@ CREATE TABLE IF NOT EXISTS chats (
     chat_identifier TEXT,
     display_name TEXT,
     participant_count INTEGER,
-    last_message_time INTEGER
+    last_message_time INTEGER,
+    deleted_at INTEGER
 );
 
 -- Contact information from Messages database
@@ -95,10 +162,15 @@ CREATE TABLE IF NOT EXISTS contacts (
     record_hash TEXT UNIQUE NOT NULL,
     handle_id TEXT NOT NULL,
     display_name TEXT,
-    service TEXT
+    service TEXT,
+    deleted_at INTEGER
 );
 
 -- Podcast listening history
+-- `guid`/`enclosure_url`/`description`/`show_notes` are left NULL by the local MTLibrary.sqlite
+-- cache (see collectors::podcasts) and backfilled by the optional RSS feed sync (see
+-- collectors::podcast_feed, gated behind the `rss` Cargo feature), which also matches rows
+-- against them to correct/enrich rather than duplicate.
 CREATE TABLE IF NOT EXISTS podcast_episodes (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     record_hash TEXT UNIQUE NOT NULL,
@@ -109,7 +181,24 @@ CREATE TABLE IF NOT EXISTS podcast_episodes (
     played_seconds REAL,
     play_count INTEGER,
     last_played_at INTEGER,
-    published_at INTEGER
+    published_at INTEGER,
+    guid TEXT,
+    enclosure_url TEXT,
+    description TEXT,
+    show_notes TEXT,
+    deleted_at INTEGER
+);
+
+-- Audio-file download progress for podcast episodes (see collectors::podcast_downloads),
+-- keyed by the episode's own record_hash so a re-run can tell a completed download apart from
+-- one still in flight (or never started) without re-checking the filesystem.
+CREATE TABLE IF NOT EXISTS podcast_downloads (
+    record_hash TEXT PRIMARY KEY NOT NULL,
+    local_path TEXT NOT NULL,
+    bytes_total INTEGER,
+    bytes_done INTEGER DEFAULT 0,
+    status TEXT NOT NULL DEFAULT 'pending',
+    completed_at INTEGER
 );
 
 -- Podcast shows/subscriptions
@@ -120,7 +209,8 @@ CREATE TABLE IF NOT EXISTS podcast_shows (
     author TEXT,
     feed_url TEXT,
     subscribed_at INTEGER,
-    episode_count INTEGER
+    episode_count INTEGER,
+    deleted_at INTEGER
 );
 
 -- Intents/Siri actions from knowledgeC
@@ -130,7 +220,8 @@ CREATE TABLE IF NOT EXISTS intents (
     intent_class TEXT,
     intent_verb TEXT,
     bundle_id TEXT,
-    timestamp INTEGER NOT NULL
+    timestamp INTEGER NOT NULL,
+    deleted_at INTEGER
 );
 
 -- Display state (screen on/off)
@@ -140,7 +231,8 @@ CREATE TABLE IF NOT EXISTS display_state (
     is_backlit INTEGER,
     start_time INTEGER NOT NULL,
     end_time INTEGER,
-    duration_seconds REAL
+    duration_seconds REAL,
+    deleted_at INTEGER
 );
 
 -- Create indexes for common queries
@@ -153,6 +245,11 @@ CREATE INDEX IF NOT EXISTS idx_contacts_handle ON contacts(handle_id);
 CREATE INDEX IF NOT EXISTS idx_notifications_time ON notifications(timestamp);
 CREATE INDEX IF NOT EXISTS idx_bluetooth_time ON bluetooth_connections(start_time);
 CREATE INDEX IF NOT EXISTS idx_podcast_episodes_played ON podcast_episodes(last_played_at);
+CREATE INDEX IF NOT EXISTS idx_podcast_episodes_guid ON podcast_episodes(guid);
+CREATE INDEX IF NOT EXISTS idx_podcast_episodes_enclosure ON podcast_episodes(enclosure_url);
+CREATE INDEX IF NOT EXISTS idx_source_provenance_collector ON source_provenance(collector);
+CREATE INDEX IF NOT EXISTS idx_knowledgec_events_stream_time ON knowledgec_events(stream_name, start_time);
+CREATE INDEX IF NOT EXISTS idx_run_changesets_run_id ON run_changesets(run_id);
 "#;
 
 /// Initialize the database with the schema
@@ -186,6 +283,7 @@ mod tests {
         assert!(tables.contains(&"chats".to_string()));
         assert!(tables.contains(&"podcast_episodes".to_string()));
         assert!(tables.contains(&"extraction_runs".to_string()));
+        assert!(tables.contains(&"extraction_state".to_string()));
     }
 
     #[test]
@@ -0,0 +1,308 @@
+//! Full-text search over the unified database's text columns, backed by SQLite FTS5.
+//!
+//! Exocore-store layers a Tantivy-backed query interface over its own store; the equivalent
+//! here is a SQLite FTS5 "external content" virtual table per searchable table — `messages`,
+//! `web_visits`, `podcast_episodes` — kept in sync with triggers so the index stays current as
+//! new rows are extracted, without duplicating the text itself into the index. A query may be
+//! scoped to one collector with a `<collector>:` prefix (`messages:dinner plans`); with no
+//! prefix it searches every indexed table and merges results by relevance.
+
+use crate::error::{Error, Result};
+use rusqlite::{Connection, OptionalExtension};
+
+/// One table this module knows how to index, and the text columns FTS5 should tokenize.
+struct SearchSpec {
+    /// Table name, and the prefix a query uses to scope itself to just this table.
+    table: &'static str,
+    /// FTS5 virtual table name.
+    fts_table: &'static str,
+    columns: &'static [&'static str],
+}
+
+const SEARCHABLE_TABLES: &[SearchSpec] = &[
+    SearchSpec {
+        table: "messages",
+        fts_table: "messages_fts",
+        columns: &["text"],
+    },
+    SearchSpec {
+        table: "web_visits",
+        fts_table: "web_visits_fts",
+        columns: &["title", "url"],
+    },
+    SearchSpec {
+        table: "podcast_episodes",
+        fts_table: "podcast_episodes_fts",
+        columns: &["episode_title", "show_title", "description", "show_notes"],
+    },
+];
+
+/// One search result: which table/row it came from, a `[...]`-highlighted snippet of the
+/// matched text, and its FTS5 bm25 rank (lower is more relevant, matching SQLite's own
+/// convention — see <https://sqlite.org/fts5.html#the_bm25_function>).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    pub table: String,
+    pub row_id: i64,
+    pub snippet: String,
+    pub rank: f64,
+}
+
+/// Create (if missing) an FTS5 external-content virtual table and sync triggers for every table
+/// in [`SEARCHABLE_TABLES`], so `INSERT`/`UPDATE`/`DELETE` against the underlying table keeps
+/// the index current incrementally rather than requiring a full [`rebuild_search_index`] after
+/// every extraction run.
+pub fn ensure_search_indexes(conn: &Connection) -> Result<()> {
+    for spec in SEARCHABLE_TABLES {
+        let columns = spec.columns.join(", ");
+        conn.execute_batch(&format!(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS {fts}
+                USING fts5({columns}, content='{table}', content_rowid='id');
+
+             CREATE TRIGGER IF NOT EXISTS {table}_ai AFTER INSERT ON {table} BEGIN
+                 INSERT INTO {fts}(rowid, {columns}) VALUES (new.id, {new_columns});
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS {table}_ad AFTER DELETE ON {table} BEGIN
+                 INSERT INTO {fts}({fts}, rowid, {columns}) VALUES ('delete', old.id, {old_columns});
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS {table}_au AFTER UPDATE ON {table} BEGIN
+                 INSERT INTO {fts}({fts}, rowid, {columns}) VALUES ('delete', old.id, {old_columns});
+                 INSERT INTO {fts}(rowid, {columns}) VALUES (new.id, {new_columns});
+             END;
+            ",
+            fts = spec.fts_table,
+            table = spec.table,
+            columns = columns,
+            new_columns = spec
+                .columns
+                .iter()
+                .map(|c| format!("new.{}", c))
+                .collect::<Vec<_>>()
+                .join(", "),
+            old_columns = spec
+                .columns
+                .iter()
+                .map(|c| format!("old.{}", c))
+                .collect::<Vec<_>>()
+                .join(", "),
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// Fully repopulate every FTS5 index in [`SEARCHABLE_TABLES`] from its underlying table's
+/// current contents, via FTS5's `'rebuild'` special command. The incremental triggers installed
+/// by [`ensure_search_indexes`] make this unnecessary in normal operation; it exists for
+/// recovering from a corrupted index or backfilling a table that predates this module.
+pub fn rebuild_search_index(conn: &Connection) -> Result<()> {
+    ensure_search_indexes(conn)?;
+    for spec in SEARCHABLE_TABLES {
+        conn.execute(
+            &format!(
+                "INSERT INTO {fts}({fts}) VALUES ('rebuild')",
+                fts = spec.fts_table
+            ),
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+/// A parsed query: an optional collector scope, and the remaining FTS5 match expression.
+struct ParsedQuery<'a> {
+    scope: Option<&'static SearchSpec>,
+    fts_query: &'a str,
+}
+
+/// Split an optional `<collector>:` prefix off `query` and validate what's left is plausible
+/// FTS5 syntax (currently: balanced double quotes, since an unterminated phrase is the most
+/// common way a free-text query breaks `MATCH`). Returns [`Error::QueryParse`] for a malformed
+/// query and [`Error::SearchIndexMissing`] for a scope naming a real but not-yet-indexed table.
+fn parse_query<'q>(conn: &Connection, query: &'q str) -> Result<ParsedQuery<'q>> {
+    let trimmed = query.trim();
+    if trimmed.is_empty() {
+        return Err(Error::QueryParse {
+            query: query.to_string(),
+            reason: "query is empty".to_string(),
+        });
+    }
+
+    let (scope, rest) = match trimmed.split_once(':') {
+        Some((prefix, rest)) if !prefix.is_empty() && !prefix.contains(char::is_whitespace) => {
+            let spec = SEARCHABLE_TABLES.iter().find(|s| s.table == prefix);
+            match spec {
+                Some(spec) => {
+                    let indexed: bool = conn
+                        .query_row(
+                            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1",
+                            rusqlite::params![spec.fts_table],
+                            |row| row.get::<_, i64>(0),
+                        )
+                        .optional()?
+                        .is_some();
+                    if !indexed {
+                        return Err(Error::SearchIndexMissing {
+                            collector: prefix.to_string(),
+                        });
+                    }
+                    (Some(spec), rest.trim())
+                }
+                None => {
+                    return Err(Error::QueryParse {
+                        query: query.to_string(),
+                        reason: format!("unknown collector prefix '{}'", prefix),
+                    })
+                }
+            }
+        }
+        _ => (None, trimmed),
+    };
+
+    if rest.is_empty() {
+        return Err(Error::QueryParse {
+            query: query.to_string(),
+            reason: "no search terms after the collector prefix".to_string(),
+        });
+    }
+
+    if rest.matches('"').count() % 2 != 0 {
+        return Err(Error::QueryParse {
+            query: query.to_string(),
+            reason: "unbalanced quotes".to_string(),
+        });
+    }
+
+    Ok(ParsedQuery {
+        scope,
+        fts_query: rest,
+    })
+}
+
+/// Run a free-text search across every indexed table (or, with a `<collector>:` prefix, just
+/// that one — see [`parse_query`]), returning up to `limit` [`Hit`]s ordered by relevance
+/// (ascending bm25 rank, best match first).
+pub fn search(conn: &Connection, query: &str, limit: usize) -> Result<Vec<Hit>> {
+    let parsed = parse_query(conn, query)?;
+    let specs: Vec<&SearchSpec> = match parsed.scope {
+        Some(spec) => vec![spec],
+        None => SEARCHABLE_TABLES.iter().collect(),
+    };
+
+    let mut hits = Vec::new();
+    for spec in specs {
+        let sql = format!(
+            "SELECT rowid, snippet({fts}, -1, '[', ']', '...', 10), bm25({fts})
+             FROM {fts} WHERE {fts} MATCH ?1 ORDER BY bm25({fts}) LIMIT ?2",
+            fts = spec.fts_table
+        );
+        let mut stmt = match conn.prepare(&sql) {
+            Ok(stmt) => stmt,
+            // The table may legitimately not exist yet for a collector that's never
+            // extracted any data; treat that the same as an unscoped query finding nothing
+            // there rather than failing the whole search.
+            Err(rusqlite::Error::SqliteFailure(_, Some(msg))) if msg.contains("no such table") => {
+                continue
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let rows = stmt
+            .query_map(rusqlite::params![parsed.fts_query, limit as i64], |row| {
+                Ok(Hit {
+                    table: spec.table.to_string(),
+                    row_id: row.get(0)?,
+                    snippet: row.get(1)?,
+                    rank: row.get(2)?,
+                })
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        hits.extend(rows);
+    }
+
+    hits.sort_by(|a, b| {
+        a.rank
+            .partial_cmp(&b.rank)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    hits.truncate(limit);
+
+    Ok(hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema;
+
+    fn seeded_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::init_database(&conn).unwrap();
+        ensure_search_indexes(&conn).unwrap();
+        conn.execute(
+            "INSERT INTO messages (record_hash, text, timestamp) VALUES (?, ?, ?)",
+            rusqlite::params!["hash1", "let's get dinner tonight", 1_672_531_200i64],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO messages (record_hash, text, timestamp) VALUES (?, ?, ?)",
+            rusqlite::params!["hash2", "see you at the movies", 1_672_531_300i64],
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_search_finds_matching_row_via_trigger_sync() {
+        let conn = seeded_db();
+        let hits = search(&conn, "dinner", 10).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].table, "messages");
+        assert!(hits[0].snippet.contains('['));
+    }
+
+    #[test]
+    fn test_search_scoped_to_collector_prefix() {
+        let conn = seeded_db();
+        let hits = search(&conn, "messages:movies", 10).unwrap();
+        assert_eq!(hits.len(), 1);
+    }
+
+    #[test]
+    fn test_search_rejects_unknown_collector_prefix() {
+        let conn = seeded_db();
+        let err = search(&conn, "bogus:movies", 10).unwrap_err();
+        assert!(matches!(err, Error::QueryParse { .. }));
+    }
+
+    #[test]
+    fn test_search_rejects_unbalanced_quotes() {
+        let conn = seeded_db();
+        let err = search(&conn, "\"dinner", 10).unwrap_err();
+        assert!(matches!(err, Error::QueryParse { .. }));
+    }
+
+    #[test]
+    fn test_search_reports_missing_index_for_unindexed_collector() {
+        let conn = Connection::open_in_memory().unwrap();
+        schema::init_database(&conn).unwrap();
+        // ensure_search_indexes was never called, so messages_fts doesn't exist yet.
+        let err = search(&conn, "messages:dinner", 10).unwrap_err();
+        assert!(matches!(err, Error::SearchIndexMissing { .. }));
+    }
+
+    #[test]
+    fn test_search_update_trigger_keeps_index_in_sync() {
+        let conn = seeded_db();
+        conn.execute(
+            "UPDATE messages SET text = 'birthday party' WHERE record_hash = 'hash1'",
+            [],
+        )
+        .unwrap();
+
+        assert!(search(&conn, "dinner", 10).unwrap().is_empty());
+        assert_eq!(search(&conn, "birthday", 10).unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,263 @@
+//! Storage abstraction for the unified database's run bookkeeping.
+//!
+//! [`StorageBackend`] abstracts extraction-run bookkeeping — starting/completing a run,
+//! watermarks, source provenance — behind a trait so it isn't spelled out in terms of a raw
+//! `rusqlite::Connection` everywhere. [`SqliteBackend`] is the only implementation: it wraps the
+//! existing `unified.db` connection, and [`Collector::store`](crate::collectors::base::Collector::store)
+//! is the seam a collector would override to point bookkeeping at a different backend.
+//!
+//! This module used to advertise a non-SQLite `PostgresBackend` behind a `postgres` feature and
+//! a separate `Store` trait with `open_run`/`finish_run` and an in-memory test double. Neither
+//! ever had a real caller: [`BaseCollector::insert_dedup`](crate::collectors::base::BaseCollector::insert_dedup)
+//! — the row-insert path that makes up the bulk of every collector's `run()` — talks to
+//! `SqliteBackend` directly rather than through `store()`, and a collector's `run()` also drives
+//! the SQLite Online Backup API (`collectors::base::copy_source_db`) and an optional session
+//! changeset (`changeset`) straight against the connection. None of that has a non-SQLite or
+//! non-on-disk equivalent, so a second backend could never actually receive an extracted row —
+//! it would have been ~200 lines of dead code behind a feature flag. Both were removed rather
+//! than kept as unreachable scaffolding; `StorageBackend` stays scoped to what it actually
+//! backs today. A test wanting an in-memory store already gets one cheaply via
+//! `Connection::open_in_memory()` plus `SqliteBackend`/`schema::init_database`, as every test
+//! below does, so no separate in-memory double is needed either.
+
+use crate::error::Result;
+use crate::timestamp;
+use rusqlite::{Connection, OptionalExtension, ToSql};
+
+/// Storage backend for the unified database: deduplicated inserts plus extraction-run
+/// bookkeeping, independent of the underlying database engine.
+pub trait StorageBackend {
+    /// Run a dedup insert. Returns `Ok(true)` if the row was added, `Ok(false)` if it was
+    /// skipped as a duplicate (a unique-constraint violation on the backend's natural key).
+    fn insert_dedup(&self, sql: &str, params: &[&dyn ToSql]) -> Result<bool>;
+
+    /// Record the start of an extraction run for `source`, returning its run id.
+    fn start_extraction_run(&self, source: &str) -> Result<i64>;
+
+    /// Record the completion of an extraction run, including the collector's watermark as of
+    /// this run (`None` if the collector doesn't use watermark-based incremental extraction, or
+    /// never advanced one).
+    fn complete_extraction_run(
+        &self,
+        run_id: i64,
+        status: &str,
+        records_added: usize,
+        records_skipped: usize,
+        records_deleted: usize,
+        last_seen_watermark: Option<i64>,
+    ) -> Result<()>;
+
+    /// Get the highest source-native timestamp successfully extracted for `collector`, if any.
+    fn get_watermark(&self, collector: &str) -> Result<Option<i64>>;
+
+    /// Persist the highest source-native timestamp successfully extracted for `collector`.
+    fn set_watermark(&self, collector: &str, watermark: i64) -> Result<()>;
+
+    /// Record a chain-of-custody entry for a source database a collector just read from.
+    fn record_source_provenance(
+        &self,
+        collector: &str,
+        path: &str,
+        size_bytes: u64,
+        sha256: &str,
+        integrity_result: &str,
+    ) -> Result<()>;
+}
+
+/// Default storage backend: the local `unified.db` SQLite file.
+pub struct SqliteBackend<'a> {
+    conn: &'a Connection,
+}
+
+impl<'a> SqliteBackend<'a> {
+    pub fn new(conn: &'a Connection) -> Self {
+        Self { conn }
+    }
+
+    /// Escape hatch for code that still needs the raw connection (e.g. ad-hoc `SELECT`s used
+    /// by collectors for lookups like the knowledgeC device-id map).
+    pub fn connection(&self) -> &'a Connection {
+        self.conn
+    }
+}
+
+impl<'a> StorageBackend for SqliteBackend<'a> {
+    /// Run `sql` (expected to be an `INSERT OR IGNORE`) through a cached prepared statement
+    /// and use `Connection::changes()` to tell a fresh insert from a silently-skipped
+    /// duplicate, rather than parsing the statement fresh and matching a constraint-violation
+    /// error on every row.
+    fn insert_dedup(&self, sql: &str, params: &[&dyn ToSql]) -> Result<bool> {
+        let mut stmt = self.conn.prepare_cached(sql)?;
+        stmt.execute(params)?;
+        Ok(self.conn.changes() > 0)
+    }
+
+    fn start_extraction_run(&self, source: &str) -> Result<i64> {
+        let now = timestamp::now_unix();
+        self.conn.execute(
+            "INSERT INTO extraction_runs (started_at, source, status) VALUES (?, ?, 'running')",
+            rusqlite::params![now, source],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    fn complete_extraction_run(
+        &self,
+        run_id: i64,
+        status: &str,
+        records_added: usize,
+        records_skipped: usize,
+        records_deleted: usize,
+        last_seen_watermark: Option<i64>,
+    ) -> Result<()> {
+        let now = timestamp::now_unix();
+        self.conn.execute(
+            "UPDATE extraction_runs SET completed_at = ?, records_added = ?, records_skipped = ?, records_deleted = ?, status = ?, last_seen_watermark = ? WHERE id = ?",
+            rusqlite::params![
+                now,
+                records_added as i64,
+                records_skipped as i64,
+                records_deleted as i64,
+                status,
+                last_seen_watermark,
+                run_id
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn get_watermark(&self, collector: &str) -> Result<Option<i64>> {
+        self.conn
+            .query_row(
+                "SELECT watermark FROM extraction_state WHERE collector = ?",
+                rusqlite::params![collector],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(Into::into)
+    }
+
+    fn set_watermark(&self, collector: &str, watermark: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO extraction_state (collector, watermark) VALUES (?, ?)
+             ON CONFLICT(collector) DO UPDATE SET watermark = excluded.watermark
+             WHERE excluded.watermark > extraction_state.watermark",
+            rusqlite::params![collector, watermark],
+        )?;
+        Ok(())
+    }
+
+    fn record_source_provenance(
+        &self,
+        collector: &str,
+        path: &str,
+        size_bytes: u64,
+        sha256: &str,
+        integrity_result: &str,
+    ) -> Result<()> {
+        let now = timestamp::now_unix();
+        self.conn.execute(
+            "INSERT INTO source_provenance
+             (collector, path, size_bytes, sha256, integrity_result, extracted_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+            rusqlite::params![
+                collector,
+                path,
+                size_bytes as i64,
+                sha256,
+                integrity_result,
+                now
+            ],
+        )?;
+        Ok(())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sqlite_backend_dedup() {
+        let conn = Connection::open_in_memory().unwrap();
+        crate::schema::init_database(&conn).unwrap();
+
+        let backend = SqliteBackend::new(&conn);
+        let added = backend
+            .insert_dedup(
+                "INSERT OR IGNORE INTO contacts (record_hash, handle_id) VALUES (?, ?)",
+                rusqlite::params!["abc", "abc"],
+            )
+            .unwrap();
+        assert!(added);
+
+        let skipped = backend
+            .insert_dedup(
+                "INSERT OR IGNORE INTO contacts (record_hash, handle_id) VALUES (?, ?)",
+                rusqlite::params!["abc", "abc"],
+            )
+            .unwrap();
+        assert!(!skipped);
+    }
+
+    #[test]
+    fn test_sqlite_backend_extraction_run_lifecycle() {
+        let conn = Connection::open_in_memory().unwrap();
+        crate::schema::init_database(&conn).unwrap();
+
+        let backend = SqliteBackend::new(&conn);
+        let run_id = backend.start_extraction_run("messages").unwrap();
+        backend
+            .complete_extraction_run(run_id, "completed", 5, 1, 0, Some(1_700_000_000))
+            .unwrap();
+
+        let (status, last_seen_watermark): (String, Option<i64>) = conn
+            .query_row(
+                "SELECT status, last_seen_watermark FROM extraction_runs WHERE id = ?",
+                [run_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(status, "completed");
+        assert_eq!(last_seen_watermark, Some(1_700_000_000));
+    }
+
+    #[test]
+    fn test_sqlite_backend_watermark_only_advances() {
+        let conn = Connection::open_in_memory().unwrap();
+        crate::schema::init_database(&conn).unwrap();
+
+        let backend = SqliteBackend::new(&conn);
+        assert_eq!(backend.get_watermark("messages").unwrap(), None);
+
+        backend.set_watermark("messages", 100).unwrap();
+        assert_eq!(backend.get_watermark("messages").unwrap(), Some(100));
+
+        // A lower watermark must not regress an already-advanced one.
+        backend.set_watermark("messages", 50).unwrap();
+        assert_eq!(backend.get_watermark("messages").unwrap(), Some(100));
+
+        backend.set_watermark("messages", 200).unwrap();
+        assert_eq!(backend.get_watermark("messages").unwrap(), Some(200));
+    }
+
+    #[test]
+    fn test_sqlite_backend_record_source_provenance() {
+        let conn = Connection::open_in_memory().unwrap();
+        crate::schema::init_database(&conn).unwrap();
+
+        let backend = SqliteBackend::new(&conn);
+        backend
+            .record_source_provenance("messages", "/tmp/chat.db", 1024, "deadbeef", "ok")
+            .unwrap();
+
+        let (path, integrity_result): (String, String) = conn
+            .query_row(
+                "SELECT path, integrity_result FROM source_provenance WHERE collector = ?",
+                rusqlite::params!["messages"],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(path, "/tmp/chat.db");
+        assert_eq!(integrity_result, "ok");
+    }
+}
@@ -8,71 +8,204 @@ const APPLE_EPOCH_OFFSET: i64 = 978307200;
 /// Chrome's epoch starts at 1601-01-01 (Windows FILETIME)
 const CHROME_EPOCH_OFFSET: i64 = 11644473600;
 
-/// Convert Apple timestamp (seconds since 2001-01-01) to Unix timestamp
-pub fn apple_to_unix(apple_timestamp: f64) -> Result<i64> {
+/// A Unix timestamp with sub-second precision preserved.
+///
+/// Source epochs (Apple nanoseconds, Chrome microseconds) carry sub-second resolution that a
+/// plain `i64` of whole seconds throws away, making two events in the same second
+/// indistinguishable. `Timestamp` keeps both halves so collectors that need full precision
+/// (ordering, dedup keys) can use it, while callers that only need the second-resolution value
+/// can keep using the existing `_to_unix`/`_to_unix_opt` helpers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp {
+    pub unix_secs: i64,
+    pub nanos: u32,
+}
+
+impl Timestamp {
+    pub fn new(unix_secs: i64, nanos: u32) -> Self {
+        Self { unix_secs, nanos }
+    }
+
+    /// Total nanoseconds since the Unix epoch.
+    pub fn as_unix_nanos(&self) -> i128 {
+        self.unix_secs as i128 * 1_000_000_000 + self.nanos as i128
+    }
+
+    /// Render as an RFC 3339 string (e.g. `2023-01-01T00:00:00.123456789+00:00`).
+    pub fn as_rfc3339(&self) -> String {
+        chrono::DateTime::from_timestamp(self.unix_secs, self.nanos)
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_else(|| format!("invalid-timestamp({}.{})", self.unix_secs, self.nanos))
+    }
+}
+
+/// Convert Apple timestamp (seconds since 2001-01-01) to a lossless [`Timestamp`]
+pub fn apple_to_timestamp(apple_timestamp: f64) -> Result<Timestamp> {
     if apple_timestamp <= 0.0 {
         return Err(Error::InvalidTimestamp(format!(
             "Apple timestamp must be positive: {}",
             apple_timestamp
         )));
     }
-    Ok((apple_timestamp as i64) + APPLE_EPOCH_OFFSET)
+    let whole_secs = apple_timestamp.trunc() as i64;
+    let frac = apple_timestamp - apple_timestamp.trunc();
+    let nanos = (frac * 1_000_000_000.0).round() as u32;
+    Ok(Timestamp::new(whole_secs + APPLE_EPOCH_OFFSET, nanos))
+}
+
+/// Convert Apple timestamp (seconds since 2001-01-01) to Unix timestamp
+pub fn apple_to_unix(apple_timestamp: f64) -> Result<i64> {
+    apple_to_timestamp(apple_timestamp).map(|ts| ts.unix_secs)
 }
 
 /// Convert Apple timestamp (seconds since 2001-01-01) to Unix timestamp, returning None for invalid values
 pub fn apple_to_unix_opt(apple_timestamp: Option<f64>) -> Option<i64> {
-    apple_timestamp.and_then(|ts| {
-        if ts <= 0.0 {
-            None
-        } else {
-            Some((ts as i64) + APPLE_EPOCH_OFFSET)
-        }
-    })
+    apple_timestamp.and_then(|ts| apple_to_unix(ts).ok())
 }
 
-/// Convert Apple nanosecond timestamp to Unix timestamp
-pub fn apple_nano_to_unix(apple_nano: i64) -> Result<i64> {
+/// Convert Apple nanosecond timestamp to a lossless [`Timestamp`]
+pub fn apple_nano_to_timestamp(apple_nano: i64) -> Result<Timestamp> {
     if apple_nano <= 0 {
         return Err(Error::InvalidTimestamp(format!(
             "Apple nano timestamp must be positive: {}",
             apple_nano
         )));
     }
-    Ok((apple_nano / 1_000_000_000) + APPLE_EPOCH_OFFSET)
+    let unix_secs = (apple_nano / 1_000_000_000) + APPLE_EPOCH_OFFSET;
+    let nanos = (apple_nano % 1_000_000_000) as u32;
+    Ok(Timestamp::new(unix_secs, nanos))
+}
+
+/// Convert Apple nanosecond timestamp to Unix timestamp
+pub fn apple_nano_to_unix(apple_nano: i64) -> Result<i64> {
+    apple_nano_to_timestamp(apple_nano).map(|ts| ts.unix_secs)
 }
 
 /// Convert Apple nanosecond timestamp to Unix timestamp, returning None for invalid values
 pub fn apple_nano_to_unix_opt(apple_nano: Option<i64>) -> Option<i64> {
-    apple_nano.and_then(|ts| {
-        if ts <= 0 {
-            None
-        } else {
-            Some((ts / 1_000_000_000) + APPLE_EPOCH_OFFSET)
-        }
-    })
+    apple_nano.and_then(|ts| apple_nano_to_unix(ts).ok())
 }
 
-/// Convert Chrome/WebKit timestamp (microseconds since 1601-01-01) to Unix timestamp
-pub fn chrome_to_unix(chrome_timestamp: i64) -> Result<i64> {
+/// Convert Chrome/WebKit timestamp (microseconds since 1601-01-01) to a lossless [`Timestamp`]
+pub fn chrome_to_timestamp(chrome_timestamp: i64) -> Result<Timestamp> {
     if chrome_timestamp <= 0 {
         return Err(Error::InvalidTimestamp(format!(
             "Chrome timestamp must be positive: {}",
             chrome_timestamp
         )));
     }
-    // Chrome uses microseconds since 1601-01-01
-    Ok((chrome_timestamp / 1_000_000) - CHROME_EPOCH_OFFSET)
+    let unix_secs = (chrome_timestamp / 1_000_000) - CHROME_EPOCH_OFFSET;
+    let nanos = (chrome_timestamp % 1_000_000) as u32 * 1000;
+    Ok(Timestamp::new(unix_secs, nanos))
+}
+
+/// Convert Chrome/WebKit timestamp (microseconds since 1601-01-01) to Unix timestamp
+pub fn chrome_to_unix(chrome_timestamp: i64) -> Result<i64> {
+    chrome_to_timestamp(chrome_timestamp).map(|ts| ts.unix_secs)
 }
 
 /// Convert Chrome/WebKit timestamp to Unix timestamp, returning None for invalid values
 pub fn chrome_to_unix_opt(chrome_timestamp: Option<i64>) -> Option<i64> {
-    chrome_timestamp.and_then(|ts| {
-        if ts <= 0 {
-            None
-        } else {
-            Some((ts / 1_000_000) - CHROME_EPOCH_OFFSET)
-        }
-    })
+    chrome_timestamp.and_then(|ts| chrome_to_unix(ts).ok())
+}
+
+/// Convert a Firefox/PRTime timestamp (microseconds since the Unix epoch) to Unix timestamp
+pub fn firefox_to_unix(firefox_timestamp: i64) -> Result<i64> {
+    if firefox_timestamp <= 0 {
+        return Err(Error::InvalidTimestamp(format!(
+            "Firefox timestamp must be positive: {}",
+            firefox_timestamp
+        )));
+    }
+    Ok(firefox_timestamp / 1_000_000)
+}
+
+/// Convert a Firefox/PRTime timestamp to Unix timestamp, returning None for invalid values
+pub fn firefox_to_unix_opt(firefox_timestamp: Option<i64>) -> Option<i64> {
+    firefox_timestamp.and_then(|ts| firefox_to_unix(ts).ok())
+}
+
+/// Parse an RSS/Atom `pubDate`/`published` value, RFC 2822 format (e.g.
+/// `Wed, 02 Oct 2024 15:00:00 GMT`), into a Unix timestamp. Returns `None` rather than erroring on
+/// unparsable input, since one malformed item in an otherwise-good feed shouldn't abort the sync
+/// (see [`crate::collectors::podcast_feed`]).
+#[cfg(feature = "rss")]
+pub fn rfc2822_to_unix_opt(value: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc2822(value)
+        .ok()
+        .map(|dt| dt.timestamp())
+}
+
+/// Convert a Unix timestamp to an Apple timestamp (seconds since 2001-01-01), for pushing date
+/// filters down into a source database's own `WHERE` clause instead of filtering in Rust after
+/// a full table read.
+pub fn unix_to_apple(unix_timestamp: i64) -> f64 {
+    (unix_timestamp - APPLE_EPOCH_OFFSET) as f64
+}
+
+/// Convert a Unix timestamp to an Apple nanosecond timestamp
+pub fn unix_to_apple_nano(unix_timestamp: i64) -> i64 {
+    (unix_timestamp - APPLE_EPOCH_OFFSET) * 1_000_000_000
+}
+
+/// Convert a Unix timestamp to a Chrome/WebKit timestamp (microseconds since 1601-01-01)
+pub fn unix_to_chrome(unix_timestamp: i64) -> i64 {
+    (unix_timestamp + CHROME_EPOCH_OFFSET) * 1_000_000
+}
+
+/// Which epoch/unit a raw integer timestamp most likely represents, as guessed by
+/// [`detect_epoch`] from its magnitude.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochKind {
+    /// Unix seconds since 1970-01-01
+    UnixSeconds,
+    /// Unix milliseconds since 1970-01-01 (e.g. DevTools Protocol timestamps)
+    UnixMillis,
+    /// Apple seconds since 2001-01-01
+    AppleSeconds,
+    /// Apple nanoseconds since 2001-01-01
+    AppleNanos,
+    /// Chrome/WebKit microseconds since 1601-01-01
+    ChromeMicros,
+    /// Magnitude didn't clearly match any known epoch/unit combination
+    Unknown,
+}
+
+/// Guess which epoch and unit a raw integer timestamp uses, purely from its magnitude.
+///
+/// This is necessarily heuristic: the ranges below assume "recent" timestamps (roughly
+/// 2001-2100) and pick the first interpretation whose magnitude threshold the value reaches,
+/// checking the largest-magnitude encoding first. It is meant for best-effort CLI/debug use, not as a
+/// substitute for a collector knowing its own source format.
+pub fn detect_epoch(raw: i64) -> EpochKind {
+    let abs = raw.abs();
+
+    // Ordered from largest expected magnitude to smallest: Apple nanoseconds-since-2001 for
+    // "now" land around 7-8e17 (biggest, since it's both nanosecond-resolution and measured
+    // from a 21st-century epoch), then Chrome microseconds-since-1601 around 1.3e16-1.4e16
+    // (large unit range, but offset by 425 years rather than ~25), then Unix milliseconds
+    // around 1.7e12, then Unix seconds around 1.7e9, then Apple seconds around 7-8e8.
+    if abs >= 100_000_000_000_000_000 {
+        return EpochKind::AppleNanos;
+    }
+
+    if abs >= 1_000_000_000_000_000 {
+        return EpochKind::ChromeMicros;
+    }
+
+    if abs >= 1_000_000_000_000 {
+        return EpochKind::UnixMillis;
+    }
+
+    if abs >= 1_000_000_000 {
+        return EpochKind::UnixSeconds;
+    }
+
+    if abs >= 100_000_000 {
+        return EpochKind::AppleSeconds;
+    }
+
+    EpochKind::Unknown
 }
 
 /// Get current Unix timestamp
@@ -83,6 +216,344 @@ pub fn now_unix() -> i64 {
         .as_secs() as i64
 }
 
+/// Policy for resolving all-numeric dates where the field order is ambiguous (e.g. `01/02/03`).
+///
+/// Mirrors the `dayfirst`/`yearfirst` knobs Python's `dateutil.parser` exposes, since most
+/// ambiguity in human-entered dates comes down to these two axes. The default matches common US
+/// usage: month before day, year last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DateParseOptions {
+    /// Treat the first numeric field as the day rather than the month.
+    pub day_first: bool,
+    /// Treat the first numeric field as the year rather than the month/day.
+    pub year_first: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum DateToken {
+    Num(String),
+    Alpha(String),
+    Sep(char),
+}
+
+fn tokenize_datetime(input: &str) -> Vec<DateToken> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_ascii_digit() {
+            let mut s = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_ascii_digit() {
+                    s.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(DateToken::Num(s));
+        } else if c.is_alphabetic() {
+            let mut s = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_alphabetic() {
+                    s.push(c);
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            tokens.push(DateToken::Alpha(s));
+        } else if c.is_whitespace() {
+            chars.next();
+        } else {
+            tokens.push(DateToken::Sep(c));
+            chars.next();
+        }
+    }
+
+    tokens
+}
+
+fn month_from_word(word: &str) -> Option<u32> {
+    match word {
+        "jan" | "january" => Some(1),
+        "feb" | "february" => Some(2),
+        "mar" | "march" => Some(3),
+        "apr" | "april" => Some(4),
+        "may" => Some(5),
+        "jun" | "june" => Some(6),
+        "jul" | "july" => Some(7),
+        "aug" | "august" => Some(8),
+        "sep" | "sept" | "september" => Some(9),
+        "oct" | "october" => Some(10),
+        "nov" | "november" => Some(11),
+        "dec" | "december" => Some(12),
+        _ => None,
+    }
+}
+
+fn is_weekday_word(word: &str) -> bool {
+    matches!(
+        word,
+        "mon"
+            | "monday"
+            | "tue"
+            | "tues"
+            | "tuesday"
+            | "wed"
+            | "wednesday"
+            | "thu"
+            | "thurs"
+            | "thursday"
+            | "fri"
+            | "friday"
+            | "sat"
+            | "saturday"
+            | "sun"
+            | "sunday"
+    )
+}
+
+/// Normalize a 2-digit year using the same pivot `dateutil` uses: `00-69` -> 2000s, `70-99` -> 1900s.
+fn normalize_year(year: u32) -> i32 {
+    if year >= 100 {
+        year as i32
+    } else if year < 70 {
+        2000 + year as i32
+    } else {
+        1900 + year as i32
+    }
+}
+
+fn resolve_date_numbers(
+    nums: &[u32],
+    month_from_name: Option<u32>,
+    options: DateParseOptions,
+    default_date: chrono::NaiveDate,
+) -> std::result::Result<(i32, u32, u32), String> {
+    use chrono::Datelike;
+
+    if let Some(month) = month_from_name {
+        return match nums.len() {
+            0 => Ok((default_date.year(), month, default_date.day())),
+            1 => Ok((default_date.year(), month, nums[0])),
+            _ => {
+                let (a, b) = (nums[0], nums[1]);
+                if a > 31 {
+                    Ok((a as i32, month, b))
+                } else {
+                    Ok((normalize_year(b), month, a))
+                }
+            }
+        };
+    }
+
+    match nums.len() {
+        0 => Ok((
+            default_date.year(),
+            default_date.month(),
+            default_date.day(),
+        )),
+        1 => Ok((default_date.year(), default_date.month(), nums[0])),
+        2 => {
+            let (first, second) = (nums[0], nums[1]);
+            if options.day_first {
+                Ok((default_date.year(), second, first))
+            } else {
+                Ok((default_date.year(), first, second))
+            }
+        }
+        _ => {
+            // An explicit 4-digit year anywhere in the group overrides the day-first/year-first
+            // policy for that field, since it can't plausibly be a day or month.
+            if let Some(pos) = nums.iter().position(|&n| n >= 1000) {
+                let year = nums[pos] as i32;
+                let mut rest = nums.to_vec();
+                rest.remove(pos);
+                let (month, day) = if options.day_first {
+                    (rest[1], rest[0])
+                } else {
+                    (rest[0], rest[1])
+                };
+                Ok((year, month, day))
+            } else if options.year_first {
+                let year = normalize_year(nums[0]);
+                let (month, day) = if options.day_first {
+                    (nums[2], nums[1])
+                } else {
+                    (nums[1], nums[2])
+                };
+                Ok((year, month, day))
+            } else if options.day_first {
+                Ok((normalize_year(nums[2]), nums[1], nums[0]))
+            } else {
+                Ok((normalize_year(nums[2]), nums[0], nums[1]))
+            }
+        }
+    }
+}
+
+fn assemble_from_tokens(
+    tokens: &[DateToken],
+    options: DateParseOptions,
+    default_date: chrono::NaiveDate,
+) -> std::result::Result<chrono::NaiveDateTime, String> {
+    let mut date_nums: Vec<u32> = Vec::new();
+    let mut month: Option<u32> = None;
+    let mut time: Option<(u32, u32, u32)> = None;
+    let mut meridiem: Option<bool> = None; // Some(true) == pm
+
+    let mut i = 0;
+    while i < tokens.len() {
+        match &tokens[i] {
+            DateToken::Num(s) => {
+                if i + 2 < tokens.len() && tokens[i + 1] == DateToken::Sep(':') {
+                    if let DateToken::Num(min_s) = &tokens[i + 2] {
+                        let hour: u32 = s.parse().map_err(|_| format!("bad hour: {}", s))?;
+                        let minute: u32 = min_s
+                            .parse()
+                            .map_err(|_| format!("bad minute: {}", min_s))?;
+                        let mut second = 0u32;
+                        let mut consumed = 3;
+                        if i + 4 < tokens.len() && tokens[i + 3] == DateToken::Sep(':') {
+                            if let DateToken::Num(sec_s) = &tokens[i + 4] {
+                                second = sec_s
+                                    .parse()
+                                    .map_err(|_| format!("bad second: {}", sec_s))?;
+                                consumed = 5;
+                            }
+                        }
+                        time = Some((hour, minute, second));
+                        i += consumed;
+                        continue;
+                    }
+                }
+
+                let n: u32 = s.parse().map_err(|_| format!("bad number: {}", s))?;
+                date_nums.push(n);
+                i += 1;
+            }
+            DateToken::Alpha(word) => {
+                let lower = word.to_lowercase();
+                if let Some(m) = month_from_word(&lower) {
+                    month = Some(m);
+                } else if lower == "am" {
+                    meridiem = Some(false);
+                } else if lower == "pm" {
+                    meridiem = Some(true);
+                } else if is_weekday_word(&lower) {
+                    // Weekday names are just a label on the date; the numeric fields carry it.
+                } else if lower == "utc" || lower == "gmt" || lower == "z" {
+                    // We don't yet resolve non-UTC offsets in the fuzzy path; treat as UTC.
+                } else {
+                    return Err(format!("unrecognized token: {}", word));
+                }
+                i += 1;
+            }
+            DateToken::Sep(_) => {
+                i += 1;
+            }
+        }
+    }
+
+    // A lone number immediately followed by am/pm (e.g. "9am") is an hour, not a date field.
+    if time.is_none() && meridiem.is_some() && date_nums.len() == 1 {
+        let hour = date_nums.remove(0);
+        time = Some((hour, 0, 0));
+    }
+
+    if let (Some((hour, minute, second)), Some(pm)) = (time, meridiem) {
+        let hour12 = hour % 12;
+        time = Some((if pm { hour12 + 12 } else { hour12 }, minute, second));
+    }
+
+    let (year, month_val, day) = resolve_date_numbers(&date_nums, month, options, default_date)?;
+    let date = chrono::NaiveDate::from_ymd_opt(year, month_val, day)
+        .ok_or_else(|| format!("invalid date: {}-{}-{}", year, month_val, day))?;
+    let (hour, minute, second) = time.unwrap_or((0, 0, 0));
+    let naive_time = chrono::NaiveTime::from_hms_opt(hour, minute, second)
+        .ok_or_else(|| format!("invalid time: {}:{}:{}", hour, minute, second))?;
+
+    Ok(chrono::NaiveDateTime::new(date, naive_time))
+}
+
+fn parse_relative_keyword(
+    trimmed: &str,
+    options: DateParseOptions,
+) -> Option<std::result::Result<chrono::NaiveDateTime, String>> {
+    use chrono::Duration;
+
+    let lower = trimmed.to_lowercase();
+    let mut parts = lower.splitn(2, char::is_whitespace);
+    let first = parts.next()?;
+    let rest = parts.next().unwrap_or("").trim();
+
+    let base_date = match first {
+        "now" if rest.is_empty() => return Some(Ok(chrono::Utc::now().naive_utc())),
+        "now" => chrono::Utc::now().date_naive(),
+        "today" => chrono::Utc::now().date_naive(),
+        "yesterday" => chrono::Utc::now().date_naive() - Duration::days(1),
+        "tomorrow" => chrono::Utc::now().date_naive() + Duration::days(1),
+        _ => return None,
+    };
+
+    if rest.is_empty() {
+        return base_date
+            .and_hms_opt(0, 0, 0)
+            .map(Ok)
+            .or(Some(Err(format!("invalid date: {}", trimmed))));
+    }
+
+    let tokens = tokenize_datetime(rest);
+    Some(assemble_from_tokens(&tokens, options, base_date))
+}
+
+/// Parse a human-readable date/time string into a lossless [`Timestamp`], using the default
+/// day-first/year-first policy (month before day, year last).
+///
+/// Recognizes ISO 8601 / RFC 3339 (`2023-01-05T09:00:00Z`), RFC 2822
+/// (`Thu, 5 Jan 2023 09:00:00 +0000`), the relative keywords `now`/`today`/`yesterday`/
+/// `tomorrow` (optionally followed by a time of day), and common slash/dash/space-delimited
+/// layouts with an optional month name and AM/PM marker (`1/5/2023`, `Jan 5, 2023 9am`).
+///
+/// Missing fields (e.g. a bare time of day) are filled in from the current date. Unparseable
+/// input is reported as [`Error::InvalidTimestamp`] naming the offending token.
+pub fn parse_datetime(input: &str) -> Result<Timestamp> {
+    parse_datetime_with_options(input, DateParseOptions::default())
+}
+
+/// Like [`parse_datetime`], but with an explicit [`DateParseOptions`] for resolving ambiguous
+/// all-numeric dates.
+pub fn parse_datetime_with_options(input: &str, options: DateParseOptions) -> Result<Timestamp> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return Err(Error::InvalidTimestamp("empty input".to_string()));
+    }
+
+    if let Some(result) = parse_relative_keyword(trimmed, options) {
+        let naive = result.map_err(|msg| {
+            Error::InvalidTimestamp(format!("could not parse date '{}': {}", input, msg))
+        })?;
+        return Ok(Timestamp::new(naive.and_utc().timestamp(), 0));
+    }
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(trimmed) {
+        return Ok(Timestamp::new(dt.timestamp(), dt.timestamp_subsec_nanos()));
+    }
+
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc2822(trimmed) {
+        return Ok(Timestamp::new(dt.timestamp(), dt.timestamp_subsec_nanos()));
+    }
+
+    let tokens = tokenize_datetime(trimmed);
+    let default_date = chrono::Utc::now().date_naive();
+    let naive = assemble_from_tokens(&tokens, options, default_date).map_err(|msg| {
+        Error::InvalidTimestamp(format!("could not parse date '{}': {}", input, msg))
+    })?;
+
+    Ok(Timestamp::new(naive.and_utc().timestamp(), 0))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,5 +602,173 @@ mod tests {
         assert!(apple_to_unix(-1.0).is_err());
         assert!(apple_nano_to_unix(0).is_err());
         assert!(chrome_to_unix(0).is_err());
+        assert!(firefox_to_unix(0).is_err());
+    }
+
+    #[test]
+    fn test_firefox_to_unix() {
+        // Firefox/PRTime timestamp for 2023-01-01 00:00:00 UTC, in microseconds since the Unix epoch.
+        let firefox_ts = 1672531200_000_000i64;
+        let unix_ts = firefox_to_unix(firefox_ts).unwrap();
+        assert_eq!(unix_ts, 1672531200);
+    }
+
+    #[test]
+    fn test_firefox_to_unix_opt() {
+        assert_eq!(
+            firefox_to_unix_opt(Some(1672531200_000_000)),
+            Some(1672531200)
+        );
+        assert_eq!(firefox_to_unix_opt(Some(0)), None);
+        assert_eq!(firefox_to_unix_opt(None), None);
+    }
+
+    #[test]
+    #[cfg(feature = "rss")]
+    fn test_rfc2822_to_unix_opt() {
+        assert_eq!(
+            rfc2822_to_unix_opt("Sun, 01 Jan 2023 00:00:00 GMT"),
+            Some(1672531200)
+        );
+        assert_eq!(rfc2822_to_unix_opt("not a date"), None);
+    }
+
+    #[test]
+    fn test_apple_nano_to_timestamp_preserves_sub_second_precision() {
+        // 694224000.5 seconds past the Apple epoch, i.e. half a second into the Unix second.
+        let apple_nano = 694224000_500_000_000i64;
+        let ts = apple_nano_to_timestamp(apple_nano).unwrap();
+        assert_eq!(ts.unix_secs, 1672531200);
+        assert_eq!(ts.nanos, 500_000_000);
+    }
+
+    #[test]
+    fn test_chrome_to_timestamp_preserves_sub_second_precision() {
+        let chrome_ts = 13317004800_500_000i64;
+        let ts = chrome_to_timestamp(chrome_ts).unwrap();
+        assert_eq!(ts.unix_secs, 1672531200);
+        assert_eq!(ts.nanos, 500_000_000);
+    }
+
+    #[test]
+    fn test_apple_to_timestamp_splits_fractional_seconds() {
+        let ts = apple_to_timestamp(694224000.25).unwrap();
+        assert_eq!(ts.unix_secs, 1672531200);
+        assert_eq!(ts.nanos, 250_000_000);
+    }
+
+    #[test]
+    fn test_timestamp_as_unix_nanos() {
+        let ts = Timestamp::new(1672531200, 500_000_000);
+        assert_eq!(ts.as_unix_nanos(), 1672531200_500_000_000i128);
+    }
+
+    #[test]
+    fn test_timestamp_as_rfc3339() {
+        let ts = Timestamp::new(1672531200, 0);
+        assert_eq!(ts.as_rfc3339(), "2023-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_unix_to_apple_round_trips() {
+        let unix_ts = 1672531200;
+        let apple_ts = unix_to_apple(unix_ts);
+        assert_eq!(apple_to_unix(apple_ts).unwrap(), unix_ts);
+    }
+
+    #[test]
+    fn test_unix_to_apple_nano_round_trips() {
+        let unix_ts = 1672531200;
+        let apple_nano = unix_to_apple_nano(unix_ts);
+        assert_eq!(apple_nano_to_unix(apple_nano).unwrap(), unix_ts);
+    }
+
+    #[test]
+    fn test_unix_to_chrome_round_trips() {
+        let unix_ts = 1672531200;
+        let chrome_ts = unix_to_chrome(unix_ts);
+        assert_eq!(chrome_to_unix(chrome_ts).unwrap(), unix_ts);
+    }
+
+    #[test]
+    fn test_parse_datetime_rfc3339() {
+        let ts = parse_datetime("2023-01-01T00:00:00Z").unwrap();
+        assert_eq!(ts.unix_secs, 1672531200);
+    }
+
+    #[test]
+    fn test_parse_datetime_rfc2822() {
+        let ts = parse_datetime("Sun, 1 Jan 2023 00:00:00 +0000").unwrap();
+        assert_eq!(ts.unix_secs, 1672531200);
+    }
+
+    #[test]
+    fn test_parse_datetime_slash_date_month_first_default() {
+        // Default policy is month-first: "1/5/2023" is January 5th.
+        let ts = parse_datetime("1/5/2023").unwrap();
+        assert_eq!(ts.as_rfc3339(), "2023-01-05T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_datetime_day_first_option() {
+        let options = DateParseOptions {
+            day_first: true,
+            year_first: false,
+        };
+        let ts = parse_datetime_with_options("1/5/2023", options).unwrap();
+        assert_eq!(ts.as_rfc3339(), "2023-05-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_datetime_month_name_with_time_and_meridiem() {
+        let ts = parse_datetime("Jan 5, 2023 9:30pm").unwrap();
+        assert_eq!(ts.as_rfc3339(), "2023-01-05T21:30:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_datetime_bare_hour_meridiem() {
+        let options = DateParseOptions::default();
+        let tokens = tokenize_datetime("9am");
+        let naive = assemble_from_tokens(
+            &tokens,
+            options,
+            chrono::NaiveDate::from_ymd_opt(2023, 1, 5).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(naive.format("%H:%M:%S").to_string(), "09:00:00");
+    }
+
+    #[test]
+    fn test_parse_datetime_year_first_two_digit_year() {
+        let options = DateParseOptions {
+            day_first: false,
+            year_first: true,
+        };
+        let ts = parse_datetime_with_options("23-01-05", options).unwrap();
+        assert_eq!(ts.as_rfc3339(), "2023-01-05T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_parse_datetime_rejects_unrecognized_token() {
+        let err = parse_datetime("not a date").unwrap_err();
+        assert!(matches!(err, Error::InvalidTimestamp(_)));
+    }
+
+    #[test]
+    fn test_parse_datetime_rejects_empty_input() {
+        assert!(parse_datetime("").is_err());
+    }
+
+    #[test]
+    fn test_detect_epoch() {
+        assert_eq!(
+            detect_epoch(13_317_004_800_000_000),
+            EpochKind::ChromeMicros
+        );
+        assert_eq!(detect_epoch(694_224_000_500_000_000), EpochKind::AppleNanos);
+        assert_eq!(detect_epoch(1_672_531_200), EpochKind::UnixSeconds);
+        assert_eq!(detect_epoch(1_672_531_200_000), EpochKind::UnixMillis);
+        assert_eq!(detect_epoch(694_224_000), EpochKind::AppleSeconds);
+        assert_eq!(detect_epoch(0), EpochKind::Unknown);
     }
 }
@@ -0,0 +1,26 @@
+//! Optional `tracing` instrumentation for the extraction path, behind the `tracing` Cargo
+//! feature so library users who don't want the dependency don't pay for it.
+//!
+//! Follows the fabaccess pattern of opening a `debug_span!` around an operation (there: role
+//! checks; here: one collector's extraction of one source database) and mirrors the context
+//! already carried by [`crate::error::Error::DatabaseWithContext`],
+//! [`crate::error::Error::SqlError`], and [`crate::error::Error::IoWithContext`] — `collector`,
+//! `operation`, `source_path` — as span fields rather than only in the error's `Display` text,
+//! so a log aggregator can correlate an error event back to which collector/source produced it
+//! without parsing this crate's multi-line `Display` output.
+
+use tracing::Span;
+
+/// Open a span for one collector's extraction of one source database, carrying `collector`,
+/// `operation`, and `source_path` fields. Entering the returned span (`.entered()`) keeps it
+/// open for the scope the caller wraps, the same RAII shape as [`crate::otel::SpanGuard`]; any
+/// `tracing::error!`/`warn!` logged while it's entered is attributed to these fields by a
+/// subscriber without repeating them at each call site.
+pub fn collector_span(collector: &str, operation: &str, source_path: &str) -> Span {
+    tracing::debug_span!(
+        "collector_extract",
+        collector = collector,
+        operation = operation,
+        source_path = source_path,
+    )
+}
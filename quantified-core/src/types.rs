@@ -1,5 +1,8 @@
 //! Core types for the quantified-core library
 
+use crate::collectors::knowledgec_streams::StreamSpec;
+use crate::metrics::Metrics;
+use crate::progress::ExtractionProgress;
 use std::path::PathBuf;
 use std::time::SystemTime;
 
@@ -17,6 +20,72 @@ pub struct ExtractionConfig {
 
     /// Custom source paths (overrides defaults)
     pub custom_source_paths: Option<Vec<String>>,
+
+    /// Passphrase used to encrypt `unified.db` at rest.
+    ///
+    /// When set, `open_unified_db` encrypts the database transparently via SQLCipher if the
+    /// crate was built with the `sqlcipher` feature, or otherwise wraps the finished file in
+    /// an AES-256-GCM envelope. Leave unset to keep the existing plaintext behavior.
+    pub encryption_key: Option<String>,
+
+    /// Ignore each collector's persisted watermark and re-scan the full source database.
+    ///
+    /// Collectors that support incremental extraction normally query only rows newer than
+    /// their last successful watermark; set this when the unified database may be missing
+    /// rows the watermark has already passed (e.g. after restoring an older backup).
+    pub full_resync: bool,
+
+    /// Host of the Chrome DevTools Protocol remote debugging endpoint, used only by
+    /// [`CollectorType::ChromeLive`].
+    pub chrome_live_host: String,
+
+    /// Port of the Chrome DevTools Protocol remote debugging endpoint, used only by
+    /// [`CollectorType::ChromeLive`].
+    pub chrome_live_port: u16,
+
+    /// Extra knowledgeC `ZOBJECT` streams to capture into `knowledgec_events`, on top of
+    /// [`crate::collectors::knowledgec_streams::default_stream_specs`]. Lets a user add a stream
+    /// they've found on their own device without writing Rust.
+    pub extra_knowledgec_streams: Vec<StreamSpec>,
+
+    /// Capture a SQLite session changeset for each extraction run and persist it into
+    /// `run_changesets`, so [`crate::changeset::diff`]/[`crate::changeset::revert`] can report
+    /// what a re-run added or undo it. Off by default since it adds a little overhead to every
+    /// insert.
+    pub capture_changesets: bool,
+
+    /// After `extract`, diff the source primary keys a collector saw this run against the rows
+    /// it previously wrote and soft-delete (`deleted_at`) anything now missing, so a source-side
+    /// deletion (a removed message, a cleared Chrome history entry) is reflected instead of
+    /// lingering forever. Off by default: a partial/incremental read must never be mistaken for
+    /// "everything else was deleted", so only turn this on alongside [`Self::full_resync`] or a
+    /// collector that's certain its `seen_source_ids` covers the whole source.
+    pub reconcile_deletions: bool,
+
+    /// Progress callbacks and cancellation for this run; see [`crate::progress`]. Defaults to
+    /// every hook unset, which makes reporting/cancellation checks no-ops.
+    pub progress: ExtractionProgress,
+
+    /// Fetch each subscribed podcast show's RSS/Atom feed to backfill episode metadata (see
+    /// `collectors::podcast_feed`, behind the `rss` Cargo feature). On by default when built
+    /// with that feature; set to `false` so an otherwise feed-capable build can still do a
+    /// purely offline, local-database-only run.
+    pub feeds_enabled: bool,
+
+    /// Worker threads used by `collectors::podcast_downloads::download_episodes` to fetch
+    /// episode audio concurrently.
+    pub download_concurrency: usize,
+
+    /// OTLP collector endpoint (e.g. `http://localhost:4318`) to export extraction spans and
+    /// metrics to, behind the `otel` Cargo feature (see `otel::init`). Unset by default, which
+    /// keeps a build compiled with that feature from initializing any exporter at all.
+    pub otel_endpoint: Option<String>,
+
+    /// Unified-table names (see [`CollectorType::unified_tables`]) to restrict an export to;
+    /// `None` means every table in `export::EXPORTABLE_TABLES`. `export::export_database` and
+    /// `arrow_export::write_parquet` take their table selection as an argument and do not read
+    /// this field; nothing in this crate passes it to them.
+    pub export_tables: Option<Vec<String>>,
 }
 
 impl Default for ExtractionConfig {
@@ -29,6 +98,18 @@ impl Default for ExtractionConfig {
             source_db_dir,
             verbose: true,
             custom_source_paths: None,
+            encryption_key: None,
+            full_resync: false,
+            chrome_live_host: "127.0.0.1".to_string(),
+            chrome_live_port: 9222,
+            extra_knowledgec_streams: Vec::new(),
+            capture_changesets: false,
+            reconcile_deletions: false,
+            progress: ExtractionProgress::default(),
+            feeds_enabled: true,
+            download_concurrency: 4,
+            otel_endpoint: None,
+            export_tables: None,
         }
     }
 }
@@ -42,6 +123,18 @@ impl ExtractionConfig {
             source_db_dir,
             verbose: true,
             custom_source_paths: None,
+            encryption_key: None,
+            full_resync: false,
+            chrome_live_host: "127.0.0.1".to_string(),
+            chrome_live_port: 9222,
+            extra_knowledgec_streams: Vec::new(),
+            capture_changesets: false,
+            reconcile_deletions: false,
+            progress: ExtractionProgress::default(),
+            feeds_enabled: true,
+            download_concurrency: 4,
+            otel_endpoint: None,
+            export_tables: None,
         }
     }
 
@@ -56,6 +149,75 @@ impl ExtractionConfig {
         self.custom_source_paths = Some(paths);
         self
     }
+
+    /// Set a passphrase to encrypt `unified.db` at rest
+    pub fn with_encryption_key(mut self, passphrase: impl Into<String>) -> Self {
+        self.encryption_key = Some(passphrase.into());
+        self
+    }
+
+    /// Ignore persisted watermarks and re-scan source databases in full
+    pub fn with_full_resync(mut self, full_resync: bool) -> Self {
+        self.full_resync = full_resync;
+        self
+    }
+
+    /// Point [`CollectorType::ChromeLive`] at a Chrome remote debugging endpoint other than the
+    /// default `127.0.0.1:9222`
+    pub fn with_chrome_live_endpoint(mut self, host: impl Into<String>, port: u16) -> Self {
+        self.chrome_live_host = host.into();
+        self.chrome_live_port = port;
+        self
+    }
+
+    /// Capture additional knowledgeC streams beyond the built-in defaults, without writing Rust.
+    pub fn with_extra_knowledgec_streams(mut self, specs: Vec<StreamSpec>) -> Self {
+        self.extra_knowledgec_streams = specs;
+        self
+    }
+
+    /// Capture a SQLite session changeset for each extraction run
+    pub fn with_changeset_capture(mut self, enabled: bool) -> Self {
+        self.capture_changesets = enabled;
+        self
+    }
+
+    /// Soft-delete unified rows whose source record is missing from the latest run
+    pub fn with_reconcile_deletions(mut self, enabled: bool) -> Self {
+        self.reconcile_deletions = enabled;
+        self
+    }
+
+    /// Attach progress callbacks and/or a cancellation token to this run
+    pub fn with_progress(mut self, progress: ExtractionProgress) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Disable RSS feed sync for podcasts, keeping a feed-capable build's run purely local.
+    pub fn without_feeds(mut self) -> Self {
+        self.feeds_enabled = false;
+        self
+    }
+
+    /// Set the worker pool size for `collectors::podcast_downloads::download_episodes`.
+    pub fn with_download_concurrency(mut self, concurrency: usize) -> Self {
+        self.download_concurrency = concurrency;
+        self
+    }
+
+    /// Export extraction spans and metrics to the OTLP collector at `endpoint`, when built with
+    /// the `otel` feature.
+    pub fn with_otel_endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.otel_endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Set [`Self::export_tables`] to these unified-table names.
+    pub fn with_export_tables(mut self, tables: Vec<String>) -> Self {
+        self.export_tables = Some(tables);
+        self
+    }
 }
 
 /// Result of an extraction operation
@@ -81,6 +243,9 @@ pub struct ExtractionResult {
 
     /// Optional error message if extraction failed
     pub error_message: Option<String>,
+
+    /// Per-collector, per-sub-step metrics (rows scanned/added/skipped/dropped, duration)
+    pub metrics: Metrics,
 }
 
 impl ExtractionResult {
@@ -94,9 +259,16 @@ impl ExtractionResult {
             completed_at: None,
             status: ExtractionStatus::Running,
             error_message: None,
+            metrics: Metrics::new(),
         }
     }
 
+    /// Attach the metrics collected during this extraction
+    pub fn with_metrics(mut self, metrics: Metrics) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     /// Mark extraction as completed successfully
     pub fn complete(mut self, records_added: usize, records_skipped: usize) -> Self {
         self.records_added = records_added;
@@ -114,6 +286,16 @@ impl ExtractionResult {
         self
     }
 
+    /// Mark extraction as cancelled partway through via [`crate::progress::CancellationToken`],
+    /// keeping whatever records were committed before the cancellation took effect.
+    pub fn cancel(mut self, records_added: usize, records_skipped: usize) -> Self {
+        self.records_added = records_added;
+        self.records_skipped = records_skipped;
+        self.completed_at = Some(SystemTime::now());
+        self.status = ExtractionStatus::Cancelled;
+        self
+    }
+
     /// Get duration of extraction in seconds
     pub fn duration_secs(&self) -> Option<f64> {
         self.completed_at.and_then(|end| {
@@ -135,6 +317,9 @@ pub enum ExtractionStatus {
 
     /// Extraction failed
     Failed,
+
+    /// Extraction was cancelled partway through via a [`crate::progress::CancellationToken`]
+    Cancelled,
 }
 
 impl ExtractionStatus {
@@ -143,6 +328,48 @@ impl ExtractionStatus {
             ExtractionStatus::Running => "running",
             ExtractionStatus::Completed => "completed",
             ExtractionStatus::Failed => "failed",
+            ExtractionStatus::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Normalized category for how a browser visit was reached, shared across all browser
+/// collectors regardless of each browser's own raw encoding (Chromium's `visits.transition`
+/// bitmask, Firefox's `moz_historyvisits.visit_type` integers). Stored as `web_visits.transition`
+/// alongside the browser-native `transition_type` string, so downstream consumers (e.g. frecency
+/// ranking) can reason about "real" browsing activity without knowing each browser's encoding.
+/// Mirrored for Swift as `WebVisitTransition` in [`crate::uniffi_api`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebVisitTransition {
+    /// Followed an ordinary link on a page
+    Link,
+    /// Typed (or selected an autocomplete suggestion) in the address bar
+    Typed,
+    /// Navigated to a bookmark
+    Bookmark,
+    /// Automatically navigated to a bookmark-provided URL (e.g. browser-shipped default bookmarks)
+    AutoBookmark,
+    /// Reloaded the current page
+    Reload,
+    /// Submitted a form
+    FormSubmit,
+    /// Redirected here by the server or a previous page, without the user navigating directly
+    Redirect,
+    /// Loaded in a subframe/iframe, not a user-visible top-level navigation
+    Embedded,
+}
+
+impl WebVisitTransition {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebVisitTransition::Link => "link",
+            WebVisitTransition::Typed => "typed",
+            WebVisitTransition::Bookmark => "bookmark",
+            WebVisitTransition::AutoBookmark => "auto_bookmark",
+            WebVisitTransition::Reload => "reload",
+            WebVisitTransition::FormSubmit => "form_submit",
+            WebVisitTransition::Redirect => "redirect",
+            WebVisitTransition::Embedded => "embedded",
         }
     }
 }
@@ -156,11 +383,27 @@ pub enum CollectorType {
     /// Chrome browser history
     Chrome,
 
+    /// Live Chrome tabs over the DevTools Protocol (not included in [`CollectorType::all`];
+    /// opt in explicitly since it requires a running, debuggable Chrome instance)
+    ChromeLive,
+
     /// Apple KnowledgeC database (app usage, bluetooth, etc.)
     KnowledgeC,
 
     /// Apple Podcasts listening history
     Podcasts,
+
+    /// Firefox browser history (`places.sqlite`)
+    Firefox,
+
+    /// Safari browser history (`History.db`)
+    Safari,
+
+    /// Brave browser history (Chromium-based, same schema as [`CollectorType::Chrome`])
+    Brave,
+
+    /// Microsoft Edge browser history (Chromium-based, same schema as [`CollectorType::Chrome`])
+    Edge,
 }
 
 impl CollectorType {
@@ -169,18 +412,48 @@ impl CollectorType {
         match self {
             CollectorType::Messages => "messages",
             CollectorType::Chrome => "chrome",
+            CollectorType::ChromeLive => "chrome_live",
             CollectorType::KnowledgeC => "knowledgeC",
             CollectorType::Podcasts => "podcasts",
+            CollectorType::Firefox => "firefox",
+            CollectorType::Safari => "safari",
+            CollectorType::Brave => "brave",
+            CollectorType::Edge => "edge",
+        }
+    }
+
+    /// Which of [`crate::export::EXPORTABLE_TABLES`]' tables this collector writes to, e.g. for
+    /// building an [`ExtractionConfig::export_tables`] list from a set of collectors. `export` and
+    /// `arrow_export` keep their own table list and do not call this. [`CollectorType::ChromeLive`] shares
+    /// [`CollectorType::Chrome`]'s table since it writes into `web_visits` the same way.
+    pub fn unified_tables(&self) -> &'static [&'static str] {
+        match self {
+            CollectorType::Messages => &["messages"],
+            CollectorType::Chrome
+            | CollectorType::ChromeLive
+            | CollectorType::Firefox
+            | CollectorType::Safari
+            | CollectorType::Brave
+            | CollectorType::Edge => &["web_visits"],
+            CollectorType::KnowledgeC => &["app_usage"],
+            CollectorType::Podcasts => &["podcast_episodes"],
         }
     }
 
-    /// Get all available collector types
+    /// Get all available collector types.
+    ///
+    /// [`CollectorType::ChromeLive`] is deliberately excluded: it requires a running,
+    /// debuggable Chrome instance and must be requested explicitly.
     pub fn all() -> Vec<CollectorType> {
         vec![
             CollectorType::KnowledgeC,
             CollectorType::Messages,
             CollectorType::Chrome,
             CollectorType::Podcasts,
+            CollectorType::Firefox,
+            CollectorType::Safari,
+            CollectorType::Brave,
+            CollectorType::Edge,
         ]
     }
 
@@ -189,8 +462,13 @@ impl CollectorType {
         match s.to_lowercase().as_str() {
             "messages" => Some(CollectorType::Messages),
             "chrome" => Some(CollectorType::Chrome),
+            "chrome_live" | "chromelive" => Some(CollectorType::ChromeLive),
             "knowledgec" | "knowledge" => Some(CollectorType::KnowledgeC),
             "podcasts" => Some(CollectorType::Podcasts),
+            "firefox" => Some(CollectorType::Firefox),
+            "safari" => Some(CollectorType::Safari),
+            "brave" => Some(CollectorType::Brave),
+            "edge" => Some(CollectorType::Edge),
             _ => None,
         }
     }
@@ -204,6 +482,8 @@ impl CollectorType {
             CollectorType::Chrome => vec![
                 "~/Library/Application Support/Google/Chrome/Default/History".to_string(),
             ],
+            // No on-disk source: this collector reaches the browser over the DevTools Protocol.
+            CollectorType::ChromeLive => vec![],
             CollectorType::KnowledgeC => vec![
                 "~/Desktop/knowledgeC.db".to_string(),
                 "~/Library/Application Support/Knowledge/knowledgeC.db".to_string(),
@@ -211,6 +491,18 @@ impl CollectorType {
             CollectorType::Podcasts => vec![
                 "~/Library/Group Containers/243LU875E5.groups.com.apple.podcasts/Documents/MTLibrary.sqlite".to_string(),
             ],
+            // No reliable exact path: profile directories are named `<hash>.<name>`, so this is
+            // always resolved via `discover_firefox_places_db`'s directory scan instead.
+            CollectorType::Firefox => vec![],
+            CollectorType::Safari => vec![
+                "~/Library/Safari/History.db".to_string(),
+            ],
+            CollectorType::Brave => vec![
+                "~/Library/Application Support/BraveSoftware/Brave-Browser/Default/History".to_string(),
+            ],
+            CollectorType::Edge => vec![
+                "~/Library/Application Support/Microsoft Edge/Default/History".to_string(),
+            ],
         }
     }
 }
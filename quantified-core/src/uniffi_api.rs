@@ -3,11 +3,23 @@
 //! This module provides a Swift-friendly API using uniffi's proc-macro system.
 
 use crate::collectors;
+use crate::collectors::base::{
+    discover_brave_history_dbs, discover_edge_history_dbs, discover_firefox_places_db,
+};
 use crate::error::Error;
-use crate::types::{CollectorType, ExtractionConfig as CoreExtractionConfig};
+use crate::export::ExportFormat as CoreExportFormat;
+use crate::progress::{
+    CancellationToken as CoreCancellationToken, ExtractionProgress as CoreExtractionProgress,
+};
+use crate::types::{
+    CollectorType, ExtractionConfig as CoreExtractionConfig,
+    WebVisitTransition as CoreWebVisitTransition,
+};
 use rusqlite::Connection;
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Instant;
 
 /// Best-effort discovery for Apple Podcasts database.
@@ -16,8 +28,7 @@ use std::time::Instant;
 /// We scan `~/Library/Group Containers/` for directories ending with `.groups.com.apple.podcasts`,
 /// then look for `Documents/MTLibrary.sqlite`.
 fn discover_podcasts_db() -> Option<PathBuf> {
-    let group_containers =
-        PathBuf::from(shellexpand::tilde("~/Library/Group Containers").as_ref());
+    let group_containers = PathBuf::from(shellexpand::tilde("~/Library/Group Containers").as_ref());
 
     if !group_containers.is_dir() {
         return None;
@@ -57,9 +68,8 @@ fn discover_podcasts_db() -> Option<PathBuf> {
 ///
 /// We scan the Chrome directory and pick the most recently modified History file.
 fn discover_chrome_history_db() -> Option<PathBuf> {
-    let chrome_root = PathBuf::from(
-        shellexpand::tilde("~/Library/Application Support/Google/Chrome").as_ref(),
-    );
+    let chrome_root =
+        PathBuf::from(shellexpand::tilde("~/Library/Application Support/Google/Chrome").as_ref());
 
     if !chrome_root.is_dir() {
         return None;
@@ -105,15 +115,17 @@ fn discover_chrome_history_db() -> Option<PathBuf> {
     best.map(|(p, _)| p)
 }
 
-
-
 /// Types of data sources
-#[derive(uniffi::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(uniffi::Enum, Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
 pub enum DataSourceType {
     Messages,
     Chrome,
     KnowledgeC,
     Podcasts,
+    Firefox,
+    Safari,
+    Brave,
+    Edge,
 }
 
 impl DataSourceType {
@@ -123,6 +135,10 @@ impl DataSourceType {
             DataSourceType::Chrome => CollectorType::Chrome,
             DataSourceType::KnowledgeC => CollectorType::KnowledgeC,
             DataSourceType::Podcasts => CollectorType::Podcasts,
+            DataSourceType::Firefox => CollectorType::Firefox,
+            DataSourceType::Safari => CollectorType::Safari,
+            DataSourceType::Brave => CollectorType::Brave,
+            DataSourceType::Edge => CollectorType::Edge,
         }
     }
 
@@ -132,6 +148,10 @@ impl DataSourceType {
             DataSourceType::Chrome => "Chrome Browser",
             DataSourceType::KnowledgeC => "System Activity",
             DataSourceType::Podcasts => "Podcasts",
+            DataSourceType::Firefox => "Firefox Browser",
+            DataSourceType::Safari => "Safari Browser",
+            DataSourceType::Brave => "Brave Browser",
+            DataSourceType::Edge => "Edge Browser",
         }
         .to_string()
     }
@@ -162,6 +182,46 @@ impl DataSourceType {
                 "~/Library/Containers/com.apple.podcasts/Data/Library/Application Support/Podcasts/MTLibrary.sqlite"
                     .to_string(),
             ],
+            // No reliable exact path: profile directories are named `<hash>.<name>`, so this is
+            // always resolved via directory scanning in scan_data_sources() instead.
+            DataSourceType::Firefox => vec![],
+            DataSourceType::Safari => vec!["~/Library/Safari/History.db".to_string()],
+            DataSourceType::Brave => vec![
+                "~/Library/Application Support/BraveSoftware/Brave-Browser/Default/History"
+                    .to_string(),
+            ],
+            DataSourceType::Edge => vec![
+                "~/Library/Application Support/Microsoft Edge/Default/History".to_string(),
+            ],
+        }
+    }
+}
+
+/// Swift-facing mirror of [`crate::types::WebVisitTransition`]: the normalized category for how a
+/// `web_visits` row was reached, independent of each browser's own raw transition encoding.
+#[derive(uniffi::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebVisitTransition {
+    Link,
+    Typed,
+    Bookmark,
+    AutoBookmark,
+    Reload,
+    FormSubmit,
+    Redirect,
+    Embedded,
+}
+
+impl From<CoreWebVisitTransition> for WebVisitTransition {
+    fn from(value: CoreWebVisitTransition) -> Self {
+        match value {
+            CoreWebVisitTransition::Link => WebVisitTransition::Link,
+            CoreWebVisitTransition::Typed => WebVisitTransition::Typed,
+            CoreWebVisitTransition::Bookmark => WebVisitTransition::Bookmark,
+            CoreWebVisitTransition::AutoBookmark => WebVisitTransition::AutoBookmark,
+            CoreWebVisitTransition::Reload => WebVisitTransition::Reload,
+            CoreWebVisitTransition::FormSubmit => WebVisitTransition::FormSubmit,
+            CoreWebVisitTransition::Redirect => WebVisitTransition::Redirect,
+            CoreWebVisitTransition::Embedded => WebVisitTransition::Embedded,
         }
     }
 }
@@ -183,6 +243,8 @@ pub struct ExtractionConfig {
     pub output_dir: String,
     pub enabled_sources: Vec<DataSourceType>,
     pub verbose: bool,
+    /// Ignore persisted watermarks and re-scan source databases in full
+    pub full_resync: bool,
 }
 
 impl ExtractionConfig {
@@ -195,28 +257,32 @@ impl ExtractionConfig {
             output_dir,
             source_db_dir,
             verbose: self.verbose,
-            custom_source_paths: None,
+            full_resync: self.full_resync,
+            ..Default::default()
         }
     }
 }
 
 /// Result for a single data source
-#[derive(uniffi::Record, Debug, Clone)]
+#[derive(uniffi::Record, Debug, Clone, serde::Serialize)]
 pub struct SourceResult {
     pub source_type: DataSourceType,
     pub source_name: String,
     pub records_added: u64,
     pub records_skipped: u64,
+    /// Rows seen but dropped during extraction (missing key or unparsable timestamp).
+    pub records_dropped: u64,
     pub success: bool,
     pub error_message: Option<String>,
 }
 
 /// Results from an extraction operation
-#[derive(uniffi::Record, Debug, Clone)]
+#[derive(uniffi::Record, Debug, Clone, serde::Serialize)]
 pub struct ExtractionReport {
     pub results: Vec<SourceResult>,
     pub total_records_added: u64,
     pub total_records_skipped: u64,
+    pub total_records_dropped: u64,
     pub duration_seconds: f64,
     pub success: bool,
     pub error_message: Option<String>,
@@ -254,14 +320,39 @@ pub enum ExtractionError {
 impl From<Error> for ExtractionError {
     fn from(err: Error) -> Self {
         match err {
-            Error::Database(e) => ExtractionError::DatabaseError {
-                msg: e.to_string(),
+            Error::Database(e) => ExtractionError::DatabaseError { msg: e.to_string() },
+            Error::DatabaseWithContext {
+                operation,
+                source_path,
+                error,
+                suggestion,
+            } => ExtractionError::DatabaseError {
+                msg: format!(
+                    "{} (source: {}): {} — {}",
+                    operation, source_path, error, suggestion
+                ),
+            },
+            Error::SqlError {
+                operation,
+                query,
+                error,
+                suggestion,
+            } => ExtractionError::DatabaseError {
+                msg: format!(
+                    "{} (query: {}): {} — {}",
+                    operation, query, error, suggestion
+                ),
             },
-            Error::Io(e) => ExtractionError::Other {
-                msg: e.to_string(),
+            Error::Io(e) => ExtractionError::Other { msg: e.to_string() },
+            Error::IoWithContext {
+                operation,
+                path,
+                error,
+            } => ExtractionError::Other {
+                msg: format!("IO error during {} at {}: {}", operation, path, error),
             },
-            Error::SourceNotFound => ExtractionError::SourceNotFound {
-                msg: "Source database not found".to_string(),
+            Error::SourceNotFound { paths } => ExtractionError::SourceNotFound {
+                msg: format!("Source database not found\n  Searched paths:\n{}", paths),
             },
             Error::CopyFailed(msg) => ExtractionError::ExtractionFailed { msg },
             Error::PermissionDenied { path } => ExtractionError::PermissionDenied {
@@ -273,6 +364,75 @@ impl From<Error> for ExtractionError {
             Error::InvalidTimestamp(msg) => ExtractionError::Other { msg },
             Error::ExtractionFailed(msg) => ExtractionError::ExtractionFailed { msg },
             Error::UnsupportedCollector(msg) => ExtractionError::Other { msg },
+            Error::IntegrityCheckFailed { path, detail } => ExtractionError::ExtractionFailed {
+                msg: format!("Integrity check failed for {}: {}", path.display(), detail),
+            },
+            Error::Cancelled => ExtractionError::Other {
+                msg: "Extraction cancelled".to_string(),
+            },
+            Error::ColumnTypeMismatch {
+                table,
+                column,
+                expected,
+                actual,
+                row_id,
+            } => ExtractionError::Other {
+                msg: format!(
+                    "{}.{} has the wrong type: expected {}, got {} (row {:?})",
+                    table, column, expected, actual, row_id
+                ),
+            },
+            Error::ValueOutOfRange {
+                table,
+                column,
+                value,
+                target_type,
+            } => ExtractionError::Other {
+                msg: format!(
+                    "{}.{} value {} does not fit in {}",
+                    table, column, value, target_type
+                ),
+            },
+            Error::Corruption {
+                database,
+                detail,
+                check_output,
+            } => ExtractionError::ExtractionFailed {
+                msg: format!(
+                    "{} is corrupt: {}\n  check_output: {:?}",
+                    database.display(),
+                    detail,
+                    check_output
+                ),
+            },
+            Error::ChecksumMismatch {
+                path,
+                expected,
+                actual,
+            } => ExtractionError::ExtractionFailed {
+                msg: format!(
+                    "Checksum mismatch for {}: expected {}, got {}",
+                    path.display(),
+                    expected,
+                    actual
+                ),
+            },
+            Error::RetriesExhausted {
+                operation,
+                attempts,
+                last_error,
+            } => ExtractionError::ExtractionFailed {
+                msg: format!(
+                    "{} failed after {} attempt(s): {}",
+                    operation, attempts, last_error
+                ),
+            },
+            Error::QueryParse { query, reason } => ExtractionError::Other {
+                msg: format!("Could not parse search query '{}': {}", query, reason),
+            },
+            Error::SearchIndexMissing { collector } => ExtractionError::Other {
+                msg: format!("No search index has been built for '{}' yet", collector),
+            },
         }
     }
 }
@@ -285,6 +445,10 @@ pub fn scan_data_sources() -> Vec<DataSourceInfo> {
         DataSourceType::Chrome,
         DataSourceType::KnowledgeC,
         DataSourceType::Podcasts,
+        DataSourceType::Firefox,
+        DataSourceType::Safari,
+        DataSourceType::Brave,
+        DataSourceType::Edge,
     ];
 
     sources
@@ -347,6 +511,33 @@ pub fn scan_data_sources() -> Vec<DataSourceInfo> {
                             set_metadata(&path);
                         }
                     }
+                    DataSourceType::Firefox => {
+                        if let Some(path) = discover_firefox_places_db() {
+                            info.path = Some(path.display().to_string());
+                            info.accessible = true;
+                            set_metadata(&path);
+                        }
+                    }
+                    DataSourceType::Brave => {
+                        if let Some((path, _)) = discover_brave_history_dbs()
+                            .into_iter()
+                            .max_by_key(|(p, _)| fs::metadata(p).and_then(|m| m.modified()).ok())
+                        {
+                            info.path = Some(path.display().to_string());
+                            info.accessible = true;
+                            set_metadata(&path);
+                        }
+                    }
+                    DataSourceType::Edge => {
+                        if let Some((path, _)) = discover_edge_history_dbs()
+                            .into_iter()
+                            .max_by_key(|(p, _)| fs::metadata(p).and_then(|m| m.modified()).ok())
+                        {
+                            info.path = Some(path.display().to_string());
+                            info.accessible = true;
+                            set_metadata(&path);
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -367,6 +558,7 @@ fn create_source_result(
             source_name: source_type.name(),
             records_added: result.records_added as u64,
             records_skipped: result.records_skipped as u64,
+            records_dropped: result.metrics.total_dropped() as u64,
             success: true,
             error_message: None,
         },
@@ -375,45 +567,163 @@ fn create_source_result(
             source_name: source_type.name(),
             records_added: 0,
             records_skipped: 0,
+            records_dropped: 0,
             success: false,
             error_message: Some(e.to_string()),
         },
     }
 }
 
+/// Swift-facing handle to cancel an in-progress [`extract_all_data`]/[`extract_single_source`]
+/// call from another thread (e.g. a "Cancel" button on a progress UI). Wraps a
+/// [`crate::progress::CancellationToken`]; see that type for the actual stop/check mechanics.
+/// A run that's cancelled this way returns a [`ExtractionReport`] covering only the sources
+/// extracted before the cancellation took effect, with `success: false`.
+#[derive(uniffi::Object)]
+pub struct ExtractionHandle {
+    token: CoreCancellationToken,
+}
+
+#[uniffi::export]
+impl ExtractionHandle {
+    /// Create a new, not-yet-cancelled handle.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {
+            token: CoreCancellationToken::new(),
+        }
+    }
+
+    /// Ask the run holding this handle to stop at its next checkpoint.
+    pub fn cancel(&self) {
+        self.token.cancel();
+    }
+
+    /// Whether [`ExtractionHandle::cancel`] has been called.
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+}
+
+impl Default for ExtractionHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Swift-facing callback interface for progress updates during [`extract_all_data`]/
+/// [`extract_single_source`]; see [`crate::progress::ExtractionProgress`] for the core
+/// equivalent this is translated into. `on_source_started`/`on_progress` fire from inside the
+/// matching collector's `run()`; `on_source_finished` fires once that source's
+/// [`SourceResult`] is available, whether it succeeded, failed, or was cancelled.
+#[uniffi::export(callback_interface)]
+pub trait ExtractionObserver: Send + Sync {
+    fn on_source_started(&self, source_type: DataSourceType);
+    fn on_progress(
+        &self,
+        source_type: DataSourceType,
+        records_done: u64,
+        records_total: Option<u64>,
+    );
+    fn on_source_finished(&self, result: SourceResult);
+}
+
+/// Build the core progress hooks for one source's extraction: `on_source_started`/`on_progress`
+/// forward to `observer` tagged with `source_type` (the core hooks only know a source's string
+/// name, which `extract_all_data`/`extract_single_source` already know without asking); the
+/// cancellation token is shared across every source in this call so cancelling mid-run stops the
+/// whole thing rather than just the source in flight.
+fn build_progress(
+    source_type: DataSourceType,
+    observer: &Option<Arc<dyn ExtractionObserver>>,
+    handle: &Option<Arc<ExtractionHandle>>,
+) -> CoreExtractionProgress {
+    let mut progress = CoreExtractionProgress::default();
+
+    if let Some(observer) = observer {
+        let started_observer = Arc::clone(observer);
+        progress.on_source_started = Some(Arc::new(move |_name: &str| {
+            started_observer.on_source_started(source_type);
+        }));
+
+        let progress_observer = Arc::clone(observer);
+        progress.on_progress = Some(Arc::new(
+            move |_name: &str, records_done: usize, records_total: Option<usize>| {
+                progress_observer.on_progress(
+                    source_type,
+                    records_done as u64,
+                    records_total.map(|total| total as u64),
+                );
+            },
+        ));
+    }
+
+    if let Some(handle) = handle {
+        progress.cancellation = Some(handle.token.clone());
+    }
+
+    progress
+}
+
 /// Extract data from all enabled sources
 #[uniffi::export]
-pub fn extract_all_data(config: ExtractionConfig) -> Result<ExtractionReport, ExtractionError> {
+pub fn extract_all_data(
+    config: ExtractionConfig,
+    observer: Option<Box<dyn ExtractionObserver>>,
+    handle: Option<Arc<ExtractionHandle>>,
+) -> Result<ExtractionReport, ExtractionError> {
     let start = Instant::now();
     let core_config = config.to_core_config();
+    let observer: Option<Arc<dyn ExtractionObserver>> = observer.map(Arc::from);
 
     // Ensure output directory exists
-    std::fs::create_dir_all(&core_config.output_dir).map_err(|e| {
-        ExtractionError::InvalidPath {
-            msg: format!("Failed to create output directory: {}", e),
-        }
+    std::fs::create_dir_all(&core_config.output_dir).map_err(|e| ExtractionError::InvalidPath {
+        msg: format!("Failed to create output directory: {}", e),
     })?;
 
     // Open unified database
-    let unified_db = crate::open_unified_db(&core_config.output_dir)?;
+    let unified_db = crate::open_unified_db(&core_config)?;
 
     let mut results = Vec::new();
     let mut total_added = 0u64;
     let mut total_skipped = 0u64;
+    let mut total_dropped = 0u64;
     let mut overall_success = true;
 
-    // Extract from each enabled source
+    // Extract from each enabled source, stopping early if the caller cancelled between sources.
     for source_type in config.enabled_sources.iter() {
+        if handle.as_ref().is_some_and(|h| h.is_cancelled()) {
+            overall_success = false;
+            break;
+        }
+
         let collector_type = source_type.to_collector_type();
+        let source_config =
+            core_config
+                .clone()
+                .with_progress(build_progress(*source_type, &observer, &handle));
 
-        let collector_result = collectors::create_collector(collector_type, &core_config, &unified_db)
-            .and_then(|mut c| c.run());
+        let collector_result =
+            collectors::create_collector(collector_type, &source_config, &unified_db)
+                .and_then(|mut c| c.run());
 
         let result = create_source_result(*source_type, collector_result);
 
+        // A cancelled `run()` still returns `Ok`, so mark the result unsuccessful here.
+        let result = if handle.as_ref().is_some_and(|h| h.is_cancelled()) {
+            SourceResult { success: false, ..result }
+        } else {
+            result
+        };
+
+        if let Some(observer) = &observer {
+            observer.on_source_finished(result.clone());
+        }
+
         if result.success {
             total_added += result.records_added;
             total_skipped += result.records_skipped;
+            total_dropped += result.records_dropped;
         } else {
             overall_success = false;
         }
@@ -427,6 +737,7 @@ pub fn extract_all_data(config: ExtractionConfig) -> Result<ExtractionReport, Ex
         results,
         total_records_added: total_added,
         total_records_skipped: total_skipped,
+        total_records_dropped: total_dropped,
         duration_seconds: duration.as_secs_f64(),
         success: overall_success,
         error_message: if overall_success {
@@ -442,31 +753,47 @@ pub fn extract_all_data(config: ExtractionConfig) -> Result<ExtractionReport, Ex
 pub fn extract_single_source(
     config: ExtractionConfig,
     source_type: DataSourceType,
+    observer: Option<Box<dyn ExtractionObserver>>,
+    handle: Option<Arc<ExtractionHandle>>,
 ) -> Result<ExtractionReport, ExtractionError> {
     let start = Instant::now();
     let core_config = config.to_core_config();
+    let observer: Option<Arc<dyn ExtractionObserver>> = observer.map(Arc::from);
 
     // Ensure output directory exists
-    std::fs::create_dir_all(&core_config.output_dir).map_err(|e| {
-        ExtractionError::InvalidPath {
-            msg: format!("Failed to create output directory: {}", e),
-        }
+    std::fs::create_dir_all(&core_config.output_dir).map_err(|e| ExtractionError::InvalidPath {
+        msg: format!("Failed to create output directory: {}", e),
     })?;
 
     // Open unified database
-    let unified_db = crate::open_unified_db(&core_config.output_dir)?;
+    let unified_db = crate::open_unified_db(&core_config)?;
 
     let collector_type = source_type.to_collector_type();
-    let collector_result = collectors::create_collector(collector_type, &core_config, &unified_db)
-        .and_then(|mut c| c.run());
+    let source_config = core_config.with_progress(build_progress(source_type, &observer, &handle));
+    let collector_result =
+        collectors::create_collector(collector_type, &source_config, &unified_db)
+            .and_then(|mut c| c.run());
 
     let result = create_source_result(source_type, collector_result);
+
+    // A cancelled `run()` still returns `Ok`, so mark the result unsuccessful here.
+    let result = if handle.as_ref().is_some_and(|h| h.is_cancelled()) {
+        SourceResult { success: false, ..result }
+    } else {
+        result
+    };
+
+    if let Some(observer) = &observer {
+        observer.on_source_finished(result.clone());
+    }
+
     let duration = start.elapsed();
 
     Ok(ExtractionReport {
         results: vec![result.clone()],
         total_records_added: result.records_added,
         total_records_skipped: result.records_skipped,
+        total_records_dropped: result.records_dropped,
         duration_seconds: duration.as_secs_f64(),
         success: result.success,
         error_message: if result.success {
@@ -477,9 +804,15 @@ pub fn extract_single_source(
     })
 }
 
-/// Get statistics about the unified database
+/// Get statistics about the unified database. `encryption_key` must match the key the database
+/// was written with (`None` for a plaintext database, which is what [`extract_all_data`]/
+/// [`extract_single_source`] write: this module's [`ExtractionConfig`] carries no key), so this
+/// can decrypt it before reading.
 #[uniffi::export]
-pub fn get_database_stats(output_dir: String) -> Result<DatabaseStats, ExtractionError> {
+pub fn get_database_stats(
+    output_dir: String,
+    encryption_key: Option<String>,
+) -> Result<DatabaseStats, ExtractionError> {
     let expanded = shellexpand::tilde(&output_dir);
     let path = PathBuf::from(expanded.as_ref());
     let db_path = path.join("unified.db");
@@ -490,10 +823,13 @@ pub fn get_database_stats(output_dir: String) -> Result<DatabaseStats, Extractio
         });
     }
 
-    let conn = Connection::open(&db_path).map_err(|e| ExtractionError::DatabaseError {
-        msg: e.to_string(),
-    })?;
+    crate::with_unified_db(&path, encryption_key.as_deref(), |conn| {
+        get_database_stats_inner(conn)
+    })
+    .map_err(Into::into)
+}
 
+fn get_database_stats_inner(conn: &Connection) -> crate::Result<DatabaseStats> {
     let messages_count: i64 = conn
         .query_row("SELECT COUNT(*) FROM messages", [], |row| row.get(0))
         .unwrap_or(0);
@@ -574,3 +910,357 @@ pub fn get_database_stats(output_dir: String) -> Result<DatabaseStats, Extractio
         latest_date: latest,
     })
 }
+
+/// A URL's Mozilla Places-style frecency ranking, from [`get_top_sites`].
+#[derive(uniffi::Record, Debug, Clone)]
+pub struct SiteFrecency {
+    pub url: String,
+    pub title: Option<String>,
+    pub visit_count: u64,
+    pub frecency: u64,
+}
+
+/// Age-bucketed recency weight for a single sampled visit, mirroring Mozilla Places' frecency
+/// algorithm: a visit from the last few days counts far more than one from months ago.
+fn recency_weight(age_days: i64) -> f64 {
+    match age_days {
+        d if d <= 4 => 100.0,
+        d if d <= 14 => 70.0,
+        d if d <= 31 => 50.0,
+        d if d <= 90 => 30.0,
+        _ => 10.0,
+    }
+}
+
+/// Visit-type bonus multiplier for a single sampled visit, mirroring Mozilla Places: an explicit
+/// navigation (typed into the address bar, or a keyword/search shortcut) counts double, an
+/// ordinary followed link counts at face value, and a reload or subframe/embedded load (the user
+/// didn't actually navigate anywhere new) doesn't contribute to frecency at all.
+fn transition_bonus(transition_type: &str) -> f64 {
+    match transition_type {
+        "typed" | "keyword" | "keyword_generated" => 2.0,
+        "reload" | "auto_subframe" | "manual_subframe" | "embed" | "framed_link" => 0.0,
+        _ => 1.0,
+    }
+}
+
+/// How many of a URL's most recent visits to sample when computing its frecency, matching
+/// Mozilla Places.
+const FRECENCY_SAMPLE_SIZE: usize = 10;
+
+/// Rank URLs in `web_visits` by Mozilla Places-style frecency rather than raw recency or visit
+/// count alone, so a Swift UI can surface a "top sites" view of the user's most meaningful sites.
+///
+/// For each URL, samples up to its [`FRECENCY_SAMPLE_SIZE`] most recent (non-deleted) visits and
+/// weights each by recency bucket times visit-type bonus (see [`recency_weight`] and
+/// [`transition_bonus`]); frecency is then `ceil(total_visit_count * sum(weights) / sample_size)`,
+/// or `0` if nothing sampled scored above zero.
+#[uniffi::export]
+pub fn get_top_sites(
+    output_dir: String,
+    limit: u32,
+    encryption_key: Option<String>,
+) -> Result<Vec<SiteFrecency>, ExtractionError> {
+    let expanded = shellexpand::tilde(&output_dir);
+    let path = PathBuf::from(expanded.as_ref());
+    let db_path = path.join("unified.db");
+
+    if !db_path.exists() {
+        return Err(ExtractionError::DatabaseError {
+            msg: format!("Database not found at {}", db_path.display()),
+        });
+    }
+
+    crate::with_unified_db(&path, encryption_key.as_deref(), |conn| {
+        get_top_sites_inner(conn, limit)
+    })
+    .map_err(Into::into)
+}
+
+fn get_top_sites_inner(conn: &Connection, limit: u32) -> crate::Result<Vec<SiteFrecency>> {
+    let visit_counts: HashMap<String, i64> = {
+        let mut stmt = conn.prepare(
+            "SELECT url, COUNT(*) FROM web_visits WHERE deleted_at IS NULL GROUP BY url",
+        )?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<rusqlite::Result<_>>()?;
+        rows
+    };
+
+    // Ordered by url then most-recent-first, so the first `FRECENCY_SAMPLE_SIZE` rows seen per
+    // url while iterating below are exactly its most recent visits.
+    let mut stmt = conn.prepare(
+        "SELECT url, title, visit_time, transition_type FROM web_visits
+         WHERE deleted_at IS NULL
+         ORDER BY url, visit_time DESC",
+    )?;
+
+    let rows = stmt.query_map([], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, Option<String>>(1)?,
+            row.get::<_, i64>(2)?,
+            row.get::<_, Option<String>>(3)?,
+        ))
+    })?;
+
+    let now = crate::timestamp::now_unix();
+
+    // Per-url: (most recent non-null title seen, sum of recency*bonus weights, visits sampled).
+    let mut sampled: HashMap<String, (Option<String>, f64, usize)> = HashMap::new();
+    for row in rows {
+        let (url, title, visit_time, transition_type) = row?;
+
+        let entry = sampled.entry(url).or_insert((None, 0.0, 0));
+        if entry.2 >= FRECENCY_SAMPLE_SIZE {
+            continue;
+        }
+
+        if entry.0.is_none() {
+            entry.0 = title;
+        }
+
+        let age_days = (now - visit_time).max(0) / 86_400;
+        let bonus = transition_bonus(transition_type.as_deref().unwrap_or(""));
+        entry.1 += recency_weight(age_days) * bonus;
+        entry.2 += 1;
+    }
+
+    let mut sites: Vec<SiteFrecency> = sampled
+        .into_iter()
+        .map(|(url, (title, weighted_sum, sampled_count))| {
+            let visit_count = visit_counts.get(&url).copied().unwrap_or(0).max(0) as u64;
+            let frecency = if sampled_count == 0 || weighted_sum <= 0.0 {
+                0
+            } else {
+                (visit_count as f64 * weighted_sum / sampled_count as f64).ceil() as u64
+            };
+
+            SiteFrecency {
+                url,
+                title,
+                visit_count,
+                frecency,
+            }
+        })
+        .collect();
+
+    sites.sort_by(|a, b| b.frecency.cmp(&a.frecency));
+    sites.truncate(limit as usize);
+
+    Ok(sites)
+}
+
+/// Swift-facing mirror of [`crate::export::ExportFormat`].
+#[derive(uniffi::Enum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    #[cfg(feature = "yaml")]
+    Yaml,
+    #[cfg(feature = "csv")]
+    Csv,
+}
+
+impl ExportFormat {
+    fn to_core_format(self) -> CoreExportFormat {
+        match self {
+            ExportFormat::Json => CoreExportFormat::Json,
+            #[cfg(feature = "yaml")]
+            ExportFormat::Yaml => CoreExportFormat::Yaml,
+            #[cfg(feature = "csv")]
+            ExportFormat::Csv => CoreExportFormat::Csv,
+        }
+    }
+}
+
+/// Serialize an [`ExtractionReport`] to `path` in the given format, so a host app can hand a
+/// user their extraction results directly instead of re-deriving them from the unified database.
+#[uniffi::export]
+pub fn export_report(
+    report: ExtractionReport,
+    format: ExportFormat,
+    path: String,
+) -> Result<(), ExtractionError> {
+    let expanded = shellexpand::tilde(&path);
+    crate::export::export_value(
+        &report,
+        format.to_core_format(),
+        Path::new(expanded.as_ref()),
+    )?;
+    Ok(())
+}
+
+/// Export every table in the unified database at `output_dir` (`messages`, `web_visits`,
+/// `app_usage`, `podcast_episodes`) to its own file under `dest_dir`, so the data can be moved
+/// off-device or diffed between runs without re-querying SQLite row by row. Returns the paths
+/// written, one per table.
+#[uniffi::export]
+pub fn export_database(
+    output_dir: String,
+    format: ExportFormat,
+    dest_dir: String,
+    encryption_key: Option<String>,
+) -> Result<Vec<String>, ExtractionError> {
+    let output_dir = PathBuf::from(shellexpand::tilde(&output_dir).as_ref());
+    let dest_dir = PathBuf::from(shellexpand::tilde(&dest_dir).as_ref());
+
+    let written = crate::export::export_database(
+        &output_dir,
+        format.to_core_format(),
+        &dest_dir,
+        None,
+        encryption_key.as_deref(),
+    )?;
+
+    Ok(written
+        .into_iter()
+        .map(|p| p.display().to_string())
+        .collect())
+}
+
+/// Write every table in the unified database at `output_dir` (or, if `tables` is non-empty, just
+/// the named subset) to its own Parquet file under `dest_dir`, for loading straight into pandas/
+/// DuckDB/polars. Only available when built with the `arrow` feature. Returns the paths written.
+#[cfg(feature = "arrow")]
+#[uniffi::export]
+pub fn write_parquet(
+    output_dir: String,
+    dest_dir: String,
+    tables: Vec<String>,
+    encryption_key: Option<String>,
+) -> Result<Vec<String>, ExtractionError> {
+    let output_dir = PathBuf::from(shellexpand::tilde(&output_dir).as_ref());
+    let dest_dir = PathBuf::from(shellexpand::tilde(&dest_dir).as_ref());
+    let tables = (!tables.is_empty()).then_some(tables.as_slice());
+
+    let written = crate::arrow_export::write_parquet(
+        &output_dir,
+        &dest_dir,
+        tables,
+        encryption_key.as_deref(),
+    )?;
+
+    Ok(written
+        .into_iter()
+        .map(|p| p.display().to_string())
+        .collect())
+}
+
+/// Swift-facing mirror of [`crate::maintenance::MaintenanceMetrics`].
+#[derive(uniffi::Record, Debug, Clone)]
+pub struct MaintenanceMetrics {
+    pub messages_deduplicated: u64,
+    pub web_visits_deduplicated: u64,
+    pub app_usage_deduplicated: u64,
+    pub podcast_episodes_deduplicated: u64,
+    pub pages_vacuumed: u64,
+    pub bytes_reclaimed: u64,
+    pub duration_seconds: f64,
+}
+
+/// Remove duplicate rows (by each table's natural key), rebuild indexes, and reclaim space in
+/// the unified database at `output_dir`. Safe to call periodically after repeated
+/// [`extract_all_data`] runs to keep the on-device database small and query-fast.
+#[uniffi::export]
+pub fn run_maintenance(
+    output_dir: String,
+    encryption_key: Option<String>,
+) -> Result<MaintenanceMetrics, ExtractionError> {
+    let output_dir = PathBuf::from(shellexpand::tilde(&output_dir).as_ref());
+    let metrics = crate::maintenance::run_maintenance(&output_dir, encryption_key.as_deref())?;
+
+    Ok(MaintenanceMetrics {
+        messages_deduplicated: *metrics.rows_deduplicated.get("messages").unwrap_or(&0) as u64,
+        web_visits_deduplicated: *metrics.rows_deduplicated.get("web_visits").unwrap_or(&0) as u64,
+        app_usage_deduplicated: *metrics.rows_deduplicated.get("app_usage").unwrap_or(&0) as u64,
+        podcast_episodes_deduplicated: *metrics
+            .rows_deduplicated
+            .get("podcast_episodes")
+            .unwrap_or(&0) as u64,
+        pages_vacuumed: metrics.pages_vacuumed.max(0) as u64,
+        bytes_reclaimed: metrics.bytes_reclaimed.max(0) as u64,
+        duration_seconds: metrics.duration_seconds,
+    })
+}
+
+/// Swift-facing mirror of [`crate::search::Hit`].
+#[derive(uniffi::Record, Debug, Clone)]
+pub struct SearchHit {
+    pub table: String,
+    pub row_id: i64,
+    pub snippet: String,
+    pub rank: f64,
+}
+
+/// Run a free-text search across the unified database at `output_dir`, returning up to `limit`
+/// ranked hits. See [`crate::search::search`] for the `<collector>:` scoping prefix and query
+/// syntax. `encryption_key` must match whatever the database was extracted with (`None` for a
+/// plaintext database).
+#[uniffi::export]
+pub fn search(
+    output_dir: String,
+    query: String,
+    limit: u32,
+    encryption_key: Option<String>,
+) -> Result<Vec<SearchHit>, ExtractionError> {
+    let output_dir = PathBuf::from(shellexpand::tilde(&output_dir).as_ref());
+    let db_path = output_dir.join("unified.db");
+    if !db_path.exists() {
+        return Err(Error::database_context(
+            "search",
+            db_path.display().to_string(),
+            "database does not exist",
+            "Run an extraction first to create unified.db",
+        )
+        .into());
+    }
+
+    let hits = crate::with_unified_db(&output_dir, encryption_key.as_deref(), |conn| {
+        crate::search::search(conn, &query, limit as usize)
+    })?;
+
+    Ok(hits
+        .into_iter()
+        .map(|h| SearchHit {
+            table: h.table,
+            row_id: h.row_id,
+            snippet: h.snippet,
+            rank: h.rank,
+        })
+        .collect())
+}
+
+/// Swift-facing summary of a [`download_podcast_episodes`] call.
+#[cfg(feature = "rss")]
+#[derive(uniffi::Record, Debug, Clone)]
+pub struct DownloadSummary {
+    pub completed: u32,
+    pub failed: u32,
+    /// Episodes with no `enclosure_url` yet, or already downloaded.
+    pub skipped: u32,
+}
+
+/// Download audio for the given podcast episodes (by `record_hash`) into
+/// `output_dir/podcast_audio/`, resuming any partial download left over from a previous call.
+/// Only available when built with the `rss` feature, since it shares that feature's HTTP
+/// dependency.
+#[cfg(feature = "rss")]
+#[uniffi::export]
+pub fn download_podcast_episodes(
+    output_dir: String,
+    record_hashes: Vec<String>,
+) -> Result<DownloadSummary, ExtractionError> {
+    let output_dir = PathBuf::from(shellexpand::tilde(&output_dir).as_ref());
+    let config = CoreExtractionConfig::with_output_dir(output_dir);
+
+    let summary = crate::collectors::podcast_downloads::download_episodes(&config, &record_hashes)?;
+
+    Ok(DownloadSummary {
+        completed: summary.completed as u32,
+        failed: summary.failed as u32,
+        skipped: summary.skipped as u32,
+    })
+}
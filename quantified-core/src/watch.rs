@@ -0,0 +1,221 @@
+//! Continuous extraction via filesystem watching ("watch mode").
+//!
+//! The CLI's default mode runs each collector once and exits. Watch mode instead keeps the
+//! process alive, watches each collector's resolved source database for changes, and re-runs
+//! that collector shortly after its source settles. Because every collector already tracks its
+//! own incremental high-water-mark (see [`crate::collectors::base::Collector::watermark`]), a
+//! triggered re-run only scans rows newer than the last one it saw rather than the whole table.
+
+use crate::collectors::create_collector;
+use crate::error::{Error, Result};
+use crate::types::{CollectorType, ExtractionConfig, ExtractionResult};
+use crate::{finalize_unified_db, find_source_db, open_unified_db};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a source database's filesystem events must go quiet before we treat the write as
+/// settled and trigger an extraction.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Base backoff between retries when a source database is locked (e.g. mid-checkpoint WAL).
+const RETRY_BACKOFF: Duration = Duration::from_millis(250);
+
+/// Give up on a single triggered extraction after this many lock retries; the next filesystem
+/// event will trigger another attempt anyway.
+const MAX_RETRIES: u32 = 5;
+
+/// Watch the given collectors' source databases forever, re-extracting each one shortly after
+/// it changes on disk. Returns only on a setup error (no watchable source found, or the
+/// watcher's channel disconnects); otherwise blocks until the process is killed.
+pub fn watch(config: &ExtractionConfig, collector_types: &[CollectorType]) -> Result<()> {
+    let sources = resolve_sources(config, collector_types);
+
+    if sources.is_empty() {
+        return Err(Error::source_not_found(
+            &collector_types
+                .iter()
+                .flat_map(|c| c.default_source_paths())
+                .collect::<Vec<_>>(),
+        ));
+    }
+
+    let (tx, rx) = channel();
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(tx).map_err(|e| {
+        Error::ExtractionFailed(format!("Failed to start filesystem watcher: {}", e))
+    })?;
+
+    for path in sources.keys() {
+        // Watch the containing directory rather than the file itself: SQLite commits go
+        // through the `-wal`/`-shm` siblings, which some platforms don't report as events on
+        // the main database path.
+        let watch_target = path.parent().unwrap_or(path);
+        watcher
+            .watch(watch_target, RecursiveMode::NonRecursive)
+            .map_err(|e| {
+                Error::ExtractionFailed(format!(
+                    "Failed to watch {}: {}",
+                    watch_target.display(),
+                    e
+                ))
+            })?;
+
+        if config.verbose {
+            println!("  Watching {} ({})", path.display(), sources[path].name());
+        }
+    }
+
+    println!(
+        "\nWatch mode: {} source(s) registered. Press Ctrl+C to stop.\n",
+        sources.len()
+    );
+
+    let mut pending: HashMap<PathBuf, Instant> = HashMap::new();
+
+    loop {
+        match rx.recv_timeout(next_timeout(&pending)) {
+            Ok(Ok(event)) => {
+                for changed_path in event.paths {
+                    if let Some(source_path) = matching_source(&sources, &changed_path) {
+                        pending.insert(source_path, Instant::now());
+                    }
+                }
+            }
+            Ok(Err(e)) => eprintln!("  Watcher error: {}", e),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(Error::ExtractionFailed(
+                    "Filesystem watcher channel disconnected".to_string(),
+                ));
+            }
+        }
+
+        let settled: Vec<PathBuf> = pending
+            .iter()
+            .filter(|(_, &seen)| seen.elapsed() >= DEBOUNCE)
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        for path in settled {
+            pending.remove(&path);
+            if let Some(&collector_type) = sources.get(&path) {
+                run_with_retry(config, collector_type);
+            }
+        }
+    }
+}
+
+/// Resolve each collector's current source database path, skipping (and logging) any collector
+/// that has none.
+fn resolve_sources(
+    config: &ExtractionConfig,
+    collector_types: &[CollectorType],
+) -> HashMap<PathBuf, CollectorType> {
+    let mut sources = HashMap::new();
+
+    for &collector_type in collector_types {
+        let paths = config
+            .custom_source_paths
+            .clone()
+            .unwrap_or_else(|| collector_type.default_source_paths());
+
+        match find_source_db(&paths) {
+            Some(path) => {
+                sources.insert(path, collector_type);
+            }
+            None if config.verbose => {
+                println!(
+                    "  Skipping {}: no source database found",
+                    collector_type.name()
+                );
+            }
+            None => {}
+        }
+    }
+
+    sources
+}
+
+/// How long to block on the next watcher event before re-checking whether any pending change
+/// has settled past the debounce window.
+fn next_timeout(pending: &HashMap<PathBuf, Instant>) -> Duration {
+    pending
+        .values()
+        .map(|&seen| DEBOUNCE.saturating_sub(seen.elapsed()))
+        .min()
+        .unwrap_or(DEBOUNCE)
+}
+
+/// SQLite writes land on `<db>-wal`/`<db>-shm` siblings as often as the main file, so match on
+/// the shared file stem rather than requiring an exact path match.
+fn matching_source(
+    sources: &HashMap<PathBuf, CollectorType>,
+    changed_path: &Path,
+) -> Option<PathBuf> {
+    let changed_stem = changed_path.file_stem()?.to_str()?;
+    sources
+        .keys()
+        .find(|source_path| {
+            source_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .map(|stem| stem == changed_stem)
+                .unwrap_or(false)
+        })
+        .cloned()
+}
+
+fn run_with_retry(config: &ExtractionConfig, collector_type: CollectorType) {
+    let mut attempt = 0;
+
+    loop {
+        match run_once(config, collector_type) {
+            Ok(result) => {
+                print_tally(collector_type, &result);
+                return;
+            }
+            Err(e) if attempt < MAX_RETRIES && is_locked_error(&e) => {
+                attempt += 1;
+                thread::sleep(RETRY_BACKOFF * attempt);
+            }
+            Err(e) => {
+                eprintln!("  {} extraction failed: {}", collector_type.name(), e);
+                return;
+            }
+        }
+    }
+}
+
+fn run_once(config: &ExtractionConfig, collector_type: CollectorType) -> Result<ExtractionResult> {
+    let unified_db = open_unified_db(config)?;
+    let mut collector = create_collector(collector_type, config, &unified_db)?;
+    let result = collector.run();
+    drop(collector);
+    finalize_unified_db(config, unified_db)?;
+    result
+}
+
+fn is_locked_error(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Database(rusqlite::Error::SqliteFailure(e, _))
+            if matches!(e.code, rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked)
+    )
+}
+
+fn print_tally(collector_type: CollectorType, result: &ExtractionResult) {
+    println!(
+        "  [{}] {} Added: {:6}  Skipped: {:6}",
+        timestamp_label(),
+        collector_type.name(),
+        result.records_added,
+        result.records_skipped
+    );
+}
+
+fn timestamp_label() -> String {
+    crate::timestamp::now_unix().to_string()
+}